@@ -1,5 +1,11 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
 /// Check result status.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Status {
     Pass,
     Fail,
@@ -7,11 +13,89 @@ pub enum Status {
     Skip,
 }
 
+/// How far `vitals --fix` may go in applying a fix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Safety {
+    /// Idempotent, project-local command (e.g. `npm install`). Runs without
+    /// asking under `--yes`.
+    Safe,
+    /// Runnable, but changes the system or kills something. Always asks.
+    Confirm,
+    /// Free-form advice, never executed.
+    Manual,
+}
+
+/// A structured remediation attached to a failing check.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FixAction {
+    /// Shell command (or advice text for manual fixes).
+    pub command: String,
+    /// Directory to run the command in. `None` means the project directory.
+    pub cwd: Option<PathBuf>,
+    /// Whether the command may be automated.
+    pub safety: Safety,
+    /// Whether the command escalates privileges via `sudo`.
+    pub sudo: bool,
+}
+
+impl FixAction {
+    /// A project-local command that is safe to run unattended.
+    pub fn safe(command: impl Into<String>, cwd: &Path) -> Self {
+        Self::new(command.into(), Some(cwd.to_path_buf()), Safety::Safe)
+    }
+
+    /// A runnable command that must always be confirmed by the user.
+    /// Commands that are shell comments (`# install foo`) become manual hints.
+    pub fn confirm(command: impl Into<String>) -> Self {
+        let command = command.into();
+        let safety = if command.starts_with('#') {
+            Safety::Manual
+        } else {
+            Safety::Confirm
+        };
+        Self::new(command, None, safety)
+    }
+
+    /// Advice that is shown but never executed.
+    pub fn manual(text: impl Into<String>) -> Self {
+        Self::new(text.into(), None, Safety::Manual)
+    }
+
+    fn new(command: String, cwd: Option<PathBuf>, safety: Safety) -> Self {
+        let sudo = safety != Safety::Manual
+            && command
+                .split("&&")
+                .any(|part| part.trim_start().starts_with("sudo "));
+        FixAction {
+            command,
+            cwd,
+            safety,
+            sudo,
+        }
+    }
+
+    /// Whether `vitals --fix` can execute this action at all.
+    pub fn is_runnable(&self) -> bool {
+        self.safety != Safety::Manual
+    }
+}
+
+impl fmt::Display for FixAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.command)
+    }
+}
+
 /// A single check result to be displayed.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CheckResult {
     /// Category grouping (e.g., "Runtime", "Dependencies").
     pub category: String,
+    /// Workspace member the result belongs to (e.g., "packages/web").
+    /// `None` for the root project and for findings shared by several members.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
     /// Check name (e.g., "node", "redis", "node_modules").
     pub name: String,
     /// Pass / Fail / Warn / Skip.
@@ -21,7 +105,28 @@ pub struct CheckResult {
     /// What was expected (e.g., ">=18 (.nvmrc)").
     pub expected: String,
     /// Actionable fix suggestion.
-    pub fix: Option<String>,
+    pub fix: Option<FixAction>,
     /// Sub-items for detail (e.g., list of missing env vars).
     pub details: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_action_classification() {
+        let fix = FixAction::confirm("sudo apt install -y nodejs");
+        assert_eq!(fix.safety, Safety::Confirm);
+        assert!(fix.sudo);
+
+        let fix = FixAction::confirm("# install nodejs");
+        assert_eq!(fix.safety, Safety::Manual);
+        assert!(!fix.is_runnable());
+
+        let fix = FixAction::safe("npm install", Path::new("/tmp"));
+        assert_eq!(fix.safety, Safety::Safe);
+        assert!(!fix.sudo);
+        assert_eq!(fix.cwd.as_deref(), Some(Path::new("/tmp")));
+    }
+}
@@ -0,0 +1,347 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
+use crate::compose::{self, ComposeProject, ComposeService};
+use crate::config::VitalsConfig;
+use crate::detect::ProjectContext;
+use crate::docker::{self, Container};
+use crate::platform::Platform;
+
+pub struct ContainersCheck;
+
+impl Check for ContainersCheck {
+    fn id(&self) -> &'static str {
+        "containers"
+    }
+
+    fn category(&self) -> &'static str {
+        "Containers"
+    }
+
+    fn description(&self) -> &'static str {
+        "Compose containers running, healthy, not restarting, on the right image"
+    }
+
+    fn applies(&self, ctx: &ProjectContext, _config: &VitalsConfig) -> bool {
+        ctx.compose.as_ref().is_some_and(|p| !p.services.is_empty())
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        _config: &VitalsConfig,
+        _platform: &Platform,
+    ) -> Vec<CheckResult> {
+        match &ctx.compose {
+            Some(project) => check(project, &ctx.dir, docker::socket_path()),
+            None => vec![],
+        }
+    }
+}
+
+pub fn check(
+    project: &ComposeProject,
+    dir: &Path,
+    socket: Result<PathBuf, String>,
+) -> Vec<CheckResult> {
+    let socket = match socket {
+        Ok(socket) => socket,
+        Err(reason) => return vec![engine_result(Status::Skip, reason, None)],
+    };
+
+    let containers = match docker::project_containers(&socket, &project.name) {
+        Ok(containers) => containers,
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            return vec![engine_result(
+                Status::Warn,
+                format!("permission denied on {}", socket.display()),
+                Some(FixAction::manual(
+                    "sudo usermod -aG docker $USER (then log in again)",
+                )),
+            )];
+        }
+        Err(e)
+            if matches!(
+                e.kind(),
+                io::ErrorKind::NotFound | io::ErrorKind::ConnectionRefused
+            ) =>
+        {
+            // The services check already reports a stopped daemon.
+            return vec![engine_result(
+                Status::Skip,
+                format!("not reachable at {}", socket.display()),
+                None,
+            )];
+        }
+        Err(e) => {
+            return vec![engine_result(
+                Status::Warn,
+                format!("API error: {}", e),
+                None,
+            )];
+        }
+    };
+
+    let mut results = Vec::new();
+    for svc in &project.services {
+        let own: Vec<&Container> = containers
+            .iter()
+            .filter(|c| c.service.as_deref() == Some(svc.name.as_str()))
+            .collect();
+
+        if own.is_empty() {
+            results.push(CheckResult {
+                category: "Containers".into(),
+                package: None,
+                name: svc.name.clone(),
+                status: Status::Fail,
+                found: "not created".into(),
+                expected: expected(svc),
+                fix: Some(FixAction::safe(
+                    format!("docker compose up -d {}", svc.name),
+                    dir,
+                )),
+                details: vec![],
+            });
+            continue;
+        }
+
+        for container in &own {
+            let name = if own.len() > 1 {
+                container.name.clone()
+            } else {
+                svc.name.clone()
+            };
+            results.push(container_result(name, svc, container, dir));
+        }
+    }
+    results
+}
+
+fn container_result(
+    name: String,
+    svc: &ComposeService,
+    container: &Container,
+    dir: &Path,
+) -> CheckResult {
+    let up = FixAction::safe(format!("docker compose up -d {}", svc.name), dir);
+    let logs = FixAction::manual(format!("inspect: docker compose logs {}", svc.name));
+
+    let mut details = Vec::new();
+    let stale_image = svc
+        .image
+        .as_deref()
+        .filter(|want| !same_image(want, &container.image));
+    if let Some(want) = stale_image {
+        details.push(format!(
+            "runs {}, compose file wants {}",
+            container.image, want
+        ));
+    }
+    if container.restart_count > 0 {
+        details.push(format!("restarted {} times", container.restart_count));
+    }
+
+    let (status, found, fix) = match (container.state.as_str(), container.health.as_deref()) {
+        ("restarting", _) => (
+            Status::Fail,
+            format!("restarting ({} restarts)", container.restart_count),
+            Some(logs),
+        ),
+        ("running", Some("unhealthy")) => (Status::Fail, "running (unhealthy)".into(), Some(logs)),
+        ("running", health) => {
+            let found = match health {
+                Some(h) => format!("running ({})", h),
+                None => "running".into(),
+            };
+            if stale_image.is_some() {
+                (Status::Warn, found, Some(up))
+            } else if container.restart_count > 0 || health == Some("starting") {
+                (Status::Warn, found, Some(logs))
+            } else {
+                (Status::Pass, found, None)
+            }
+        }
+        // One-shot services (migrations, seeders) are done once they exit
+        // cleanly; a server that stopped cleanly was just stopped.
+        ("exited", _) if container.exit_code == Some(0) => {
+            if svc.ports.is_empty() && !svc.healthcheck {
+                details.push("one-shot service completed".into());
+                (Status::Pass, "exited (0)".into(), None)
+            } else {
+                (Status::Warn, "exited (0)".into(), Some(up))
+            }
+        }
+        ("exited", _) => (
+            Status::Fail,
+            match container.exit_code {
+                Some(code) => format!("exited ({})", code),
+                None => "exited".into(),
+            },
+            Some(up),
+        ),
+        (state, _) => (Status::Fail, state.to_string(), Some(up)),
+    };
+
+    CheckResult {
+        category: "Containers".into(),
+        package: None,
+        name,
+        status,
+        found,
+        expected: expected(svc),
+        fix,
+        details,
+    }
+}
+
+fn expected(svc: &ComposeService) -> String {
+    if svc.healthcheck {
+        "running (healthy)".into()
+    } else {
+        "running".into()
+    }
+}
+
+fn engine_result(status: Status, found: String, fix: Option<FixAction>) -> CheckResult {
+    CheckResult {
+        category: "Containers".into(),
+        package: None,
+        name: "docker engine".into(),
+        status,
+        found,
+        expected: "reachable".into(),
+        fix,
+        details: vec![],
+    }
+}
+
+/// Compare image references the way the daemon resolves them:
+/// `postgres` == `docker.io/library/postgres:latest`.
+fn same_image(a: &str, b: &str) -> bool {
+    fn normalize(image: &str) -> (String, String) {
+        let repo = compose::image_repo(image);
+        let repo = repo.strip_prefix("docker.io/").unwrap_or(&repo);
+        let repo = repo.strip_prefix("library/").unwrap_or(repo);
+        (repo.to_string(), compose::image_tag(image))
+    }
+    // Pinned by digest: only the repository can be compared.
+    if a.contains('@') || b.contains('@') {
+        return normalize(a).0 == normalize(b).0;
+    }
+    normalize(a) == normalize(b)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::docker::tests::fake_engine;
+
+    fn service(name: &str, image: &str, healthcheck: bool) -> ComposeService {
+        ComposeService {
+            name: name.into(),
+            image: Some(image.into()),
+            ports: vec![],
+            profiles: vec![],
+            depends_on: vec![],
+            healthcheck,
+            file: "compose.yaml".into(),
+        }
+    }
+
+    #[test]
+    fn test_container_states() {
+        let socket = fake_engine(vec![
+            (
+                "/containers/json",
+                r#"[{"Id":"db1","Labels":{"com.docker.compose.service":"db"}},
+                    {"Id":"cache1","Labels":{"com.docker.compose.service":"cache"}},
+                    {"Id":"api1","Labels":{"com.docker.compose.service":"api"}},
+                    {"Id":"migrate1","Labels":{"com.docker.compose.service":"migrate"}},
+                    {"Id":"seed1","Labels":{"com.docker.compose.service":"seed"}}]"#
+                    .into(),
+            ),
+            (
+                "/containers/db1/json",
+                r#"{"Name":"/shop-db-1","RestartCount":0,"Config":{"Image":"postgres:15"},
+                    "State":{"Status":"running","Health":{"Status":"healthy"}}}"#
+                    .into(),
+            ),
+            (
+                "/containers/cache1/json",
+                r#"{"Name":"/shop-cache-1","RestartCount":0,"Config":{"Image":"redis"},
+                    "State":{"Status":"running"}}"#
+                    .into(),
+            ),
+            (
+                "/containers/api1/json",
+                r#"{"Name":"/shop-api-1","RestartCount":7,"Config":{"Image":"shop-api"},
+                    "State":{"Status":"restarting","ExitCode":1}}"#
+                    .into(),
+            ),
+            (
+                "/containers/migrate1/json",
+                r#"{"Name":"/shop-migrate-1","RestartCount":0,"Config":{"Image":"shop-api"},
+                    "State":{"Status":"exited","ExitCode":0}}"#
+                    .into(),
+            ),
+            (
+                "/containers/seed1/json",
+                r#"{"Name":"/shop-seed-1","RestartCount":0,"Config":{"Image":"shop-api"},
+                    "State":{"Status":"exited","ExitCode":2}}"#
+                    .into(),
+            ),
+        ]);
+        let project = ComposeProject {
+            name: "shop".into(),
+            files: vec![],
+            services: vec![
+                service("db", "postgres:16", true),
+                service("cache", "docker.io/library/redis:latest", false),
+                service("api", "shop-api", false),
+                service("worker", "shop-worker", false),
+                service("migrate", "shop-api", false),
+                service("seed", "shop-api", false),
+            ],
+        };
+
+        let results = check(&project, Path::new("."), Ok(socket));
+        let summary: Vec<(&str, Status, &str)> = results
+            .iter()
+            .map(|r| (r.name.as_str(), r.status.clone(), r.found.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("db", Status::Warn, "running (healthy)"),
+                ("cache", Status::Pass, "running"),
+                ("api", Status::Fail, "restarting (7 restarts)"),
+                ("worker", Status::Fail, "not created"),
+                ("migrate", Status::Pass, "exited (0)"),
+                ("seed", Status::Fail, "exited (2)"),
+            ]
+        );
+        assert_eq!(
+            results[0].details,
+            vec!["runs postgres:15, compose file wants postgres:16"]
+        );
+    }
+
+    #[test]
+    fn test_engine_unavailable() {
+        let project = ComposeProject {
+            name: "shop".into(),
+            files: vec![],
+            services: vec![service("db", "postgres", false)],
+        };
+        let results = check(
+            &project,
+            Path::new("."),
+            Ok(PathBuf::from("/nonexistent/docker.sock")),
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].status, Status::Skip);
+    }
+}
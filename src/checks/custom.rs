@@ -1,65 +1,174 @@
-use std::process::Command;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use crate::check::{CheckResult, Status};
-use crate::config::VitalsConfig;
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
+use crate::config::{CommandConfig, VitalsConfig};
 use crate::detect::ProjectContext;
+use crate::parallel;
+use crate::platform::Platform;
+
+pub struct CommandsCheck;
+
+impl Check for CommandsCheck {
+    fn id(&self) -> &'static str {
+        "commands"
+    }
+
+    fn category(&self) -> &'static str {
+        "Commands"
+    }
+
+    fn description(&self) -> &'static str {
+        "Custom shell commands from .vitals.toml exit 0"
+    }
+
+    fn applies(&self, _ctx: &ProjectContext, config: &VitalsConfig) -> bool {
+        !config.commands.is_empty()
+    }
+
+    /// Commands enforce their own timeouts (and kill the process), so give
+    /// the check as a whole a little longer than the slowest command.
+    fn timeout(&self, config: &VitalsConfig) -> Duration {
+        let default = config.checks.timeout_for(self.id());
+        config
+            .commands
+            .iter()
+            .map(|c| command_timeout(c, default))
+            .max()
+            .unwrap_or(default)
+            + Duration::from_secs(1)
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        _platform: &Platform,
+    ) -> Vec<CheckResult> {
+        check(ctx, config)
+    }
+}
 
 pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
-    let mut results = Vec::new();
-
-    for cmd in &config.commands {
-        let output = Command::new("sh")
-            .arg("-c")
-            .arg(&cmd.run)
-            .current_dir(&ctx.dir)
-            .output();
-
-        match output {
-            Ok(out) if out.status.success() => {
-                results.push(CheckResult {
-                    category: "Commands".into(),
-                    name: cmd.name.clone(),
-                    status: Status::Pass,
-                    found: "passed".into(),
-                    expected: "exit 0".into(),
-                    fix: None,
-                    details: vec![],
-                });
-            }
-            Ok(out) => {
-                let stderr = String::from_utf8_lossy(&out.stderr).trim().to_string();
-                let detail = if stderr.is_empty() {
-                    String::from_utf8_lossy(&out.stdout).trim().to_string()
+    let default_timeout = config.checks.timeout_for("commands");
+    let workers = config
+        .checks
+        .workers
+        .unwrap_or_else(parallel::default_workers);
+
+    parallel::map(config.commands.iter().collect(), workers, |cmd| {
+        let timeout = command_timeout(cmd, default_timeout);
+        check_one(cmd, &ctx.dir, timeout)
+    })
+}
+
+fn check_one(cmd: &CommandConfig, dir: &Path, timeout: Duration) -> CheckResult {
+    match run_with_timeout(&cmd.run, dir, timeout) {
+        Ok(Some(out)) if out.status.success() => CheckResult {
+            category: "Commands".into(),
+            package: None,
+            name: cmd.name.clone(),
+            status: Status::Pass,
+            found: "passed".into(),
+            expected: "exit 0".into(),
+            fix: None,
+            details: vec![],
+        },
+        Ok(Some(out)) => {
+            let stderr = String::from_utf8_lossy(&out.stderr).trim().to_string();
+            let detail = if stderr.is_empty() {
+                String::from_utf8_lossy(&out.stdout).trim().to_string()
+            } else {
+                stderr
+            };
+            CheckResult {
+                category: "Commands".into(),
+                package: None,
+                name: cmd.name.clone(),
+                status: Status::Fail,
+                found: format!("exit {}", out.status.code().unwrap_or(-1)),
+                expected: "exit 0".into(),
+                fix: Some(FixAction::manual(format!("run: {}", cmd.run))),
+                details: if detail.is_empty() {
+                    vec![]
                 } else {
-                    stderr
-                };
-                results.push(CheckResult {
-                    category: "Commands".into(),
-                    name: cmd.name.clone(),
-                    status: Status::Fail,
-                    found: format!("exit {}", out.status.code().unwrap_or(-1)),
-                    expected: "exit 0".into(),
-                    fix: Some(format!("run: {}", cmd.run)),
-                    details: if detail.is_empty() {
-                        vec![]
-                    } else {
-                        detail.lines().take(3).map(|l| l.to_string()).collect()
-                    },
-                });
-            }
-            Err(_) => {
-                results.push(CheckResult {
-                    category: "Commands".into(),
-                    name: cmd.name.clone(),
-                    status: Status::Fail,
-                    found: "failed to execute".into(),
-                    expected: "exit 0".into(),
-                    fix: Some(format!("check command: {}", cmd.run)),
-                    details: vec![],
-                });
+                    detail.lines().take(3).map(|l| l.to_string()).collect()
+                },
             }
         }
+        Ok(None) => CheckResult {
+            category: "Commands".into(),
+            package: None,
+            name: cmd.name.clone(),
+            status: Status::Fail,
+            found: format!("timed out after {}s", timeout.as_secs()),
+            expected: "exit 0".into(),
+            fix: Some(FixAction::manual(format!(
+                "raise `timeout` for {} in .vitals.toml",
+                cmd.name
+            ))),
+            details: vec![],
+        },
+        Err(_) => CheckResult {
+            category: "Commands".into(),
+            package: None,
+            name: cmd.name.clone(),
+            status: Status::Fail,
+            found: "failed to execute".into(),
+            expected: "exit 0".into(),
+            fix: Some(FixAction::manual(format!("check command: {}", cmd.run))),
+            details: vec![],
+        },
     }
+}
+
+fn command_timeout(cmd: &CommandConfig, default: Duration) -> Duration {
+    cmd.timeout.map(Duration::from_secs).unwrap_or(default)
+}
+
+/// Run a shell command, killing it if it outlives `timeout`.
+/// Returns `Ok(None)` on timeout.
+fn run_with_timeout(run: &str, dir: &Path, timeout: Duration) -> io::Result<Option<Output>> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(run)
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
 
-    results
+    // Drain pipes on the side so a chatty command can't block on a full pipe.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(Output {
+                status,
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            }));
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
 }
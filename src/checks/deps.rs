@@ -1,13 +1,71 @@
-use crate::check::{CheckResult, Status};
-use crate::detect::ProjectContext;
+use std::path::Path;
+
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
+use crate::config::VitalsConfig;
+use crate::detect::{DepsInfo, ProjectContext, VenvInfo};
+use crate::platform::Platform;
+use crate::version;
+
+pub struct DepsCheck;
+
+impl Check for DepsCheck {
+    fn id(&self) -> &'static str {
+        "deps"
+    }
+
+    fn category(&self) -> &'static str {
+        "Dependencies"
+    }
+
+    fn description(&self) -> &'static str {
+        "Dependencies are installed (node_modules, virtualenvs, vendor)"
+    }
+
+    fn applies(&self, ctx: &ProjectContext, _config: &VitalsConfig) -> bool {
+        !ctx.deps.is_empty()
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        _config: &VitalsConfig,
+        _platform: &Platform,
+    ) -> Vec<CheckResult> {
+        check(ctx)
+    }
+}
 
 pub fn check(ctx: &ProjectContext) -> Vec<CheckResult> {
     let mut results = Vec::new();
 
     for dep in &ctx.deps {
-        if dep.exists {
+        let venv = match dep.name.as_str() {
+            "virtualenv" => check_venv_location(ctx, dep).or_else(|| {
+                let venv = ctx.venv.as_ref()?;
+                check_venv(ctx, venv, dep)
+            }),
+            _ => None,
+        };
+        if let Some(result) = venv {
+            results.push(result);
+        } else if let (true, Some(reason)) = (dep.exists, &dep.stale) {
+            // Installed, just not what the lockfile says: things mostly work
+            // until the difference matters.
             results.push(CheckResult {
                 category: "Dependencies".into(),
+                package: None,
+                name: dep.name.clone(),
+                status: Status::Warn,
+                found: "out of date".into(),
+                expected: "matches lockfile".into(),
+                fix: Some(FixAction::safe(&dep.install_cmd, &ctx.dir)),
+                details: reason.lines().map(String::from).collect(),
+            });
+        } else if dep.exists {
+            results.push(CheckResult {
+                category: "Dependencies".into(),
+                package: None,
                 name: dep.name.clone(),
                 status: Status::Pass,
                 found: "installed".into(),
@@ -18,11 +76,12 @@ pub fn check(ctx: &ProjectContext) -> Vec<CheckResult> {
         } else {
             results.push(CheckResult {
                 category: "Dependencies".into(),
+                package: None,
                 name: dep.name.clone(),
                 status: Status::Fail,
                 found: "missing".into(),
                 expected: "installed".into(),
-                fix: Some(dep.install_cmd.clone()),
+                fix: Some(FixAction::safe(&dep.install_cmd, &ctx.dir)),
                 details: vec![],
             });
         }
@@ -30,3 +89,79 @@ pub fn check(ctx: &ProjectContext) -> Vec<CheckResult> {
 
     results
 }
+
+/// A virtualenv whose base interpreter is gone, or that was built with a
+/// Python the project no longer accepts, has to be recreated.
+fn check_venv(ctx: &ProjectContext, venv: &VenvInfo, dep: &DepsInfo) -> Option<CheckResult> {
+    let recreate = FixAction::confirm(format!(
+        "rm -rf {} && {}",
+        relative(&venv.path, ctx),
+        dep.install_cmd
+    ));
+    let result = |found: String, expected: String, details: Vec<String>| CheckResult {
+        category: "Dependencies".into(),
+        package: None,
+        name: dep.name.clone(),
+        status: Status::Fail,
+        found,
+        expected,
+        fix: Some(recreate.clone()),
+        details,
+    };
+
+    if !venv.base_exists {
+        let details = venv
+            .base
+            .iter()
+            .map(|b| format!("{} no longer exists", b.display()))
+            .collect();
+        return Some(result(
+            "broken (base interpreter missing)".into(),
+            "working interpreter".into(),
+            details,
+        ));
+    }
+
+    let python = ctx.runtimes.iter().find(|r| r.name == "python")?;
+    let (found, req) = (venv.version.as_deref()?, python.version_req.as_deref()?);
+    if version::meets_requirement(found, req) {
+        return None;
+    }
+    Some(result(
+        format!("built with Python {}", found),
+        format!("Python {} ({})", req, python.source),
+        vec![],
+    ))
+}
+
+/// An in-project virtualenv the Python manager won't use: uv with
+/// `UV_PROJECT_ENVIRONMENT` set elsewhere, Poetry keeping its own in the cache.
+fn check_venv_location(ctx: &ProjectContext, dep: &DepsInfo) -> Option<CheckResult> {
+    let python = ctx.python.as_ref()?;
+    let unused = python.unused_venv.as_ref()?;
+    let expected = match &python.venv {
+        Some(path) => format!("{} ({})", relative(path, ctx), python.venv_source),
+        None => python.venv_source.clone(),
+    };
+    Some(CheckResult {
+        category: "Dependencies".into(),
+        package: None,
+        name: dep.name.clone(),
+        status: Status::Fail,
+        found: format!("{} (not used by {})", relative(unused, ctx), python.manager),
+        expected,
+        fix: Some(FixAction::safe(&dep.install_cmd, &ctx.dir)),
+        details: vec![],
+    })
+}
+
+/// `path` relative to the project directory when it is inside it. Detected
+/// paths are joined onto the directory as given ("./venv"), `ctx.dir` is
+/// canonical.
+fn relative(path: &Path, ctx: &ProjectContext) -> String {
+    path.strip_prefix(&ctx.dir)
+        .or_else(|_| path.strip_prefix("."))
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
@@ -1,6 +1,33 @@
-use crate::check::{CheckResult, Status};
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
 use crate::config::VitalsConfig;
 use crate::detect::ProjectContext;
+use crate::platform::Platform;
+
+pub struct EnvCheck;
+
+impl Check for EnvCheck {
+    fn id(&self) -> &'static str {
+        "env"
+    }
+
+    fn category(&self) -> &'static str {
+        "Environment"
+    }
+
+    fn description(&self) -> &'static str {
+        ".env file exists and has all required keys"
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        _platform: &Platform,
+    ) -> Vec<CheckResult> {
+        check(ctx, config)
+    }
+}
 
 pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
     let mut results = Vec::new();
@@ -29,11 +56,15 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
 
         results.push(CheckResult {
             category: "Environment".into(),
+            package: None,
             name: ".env".into(),
             status: Status::Fail,
             found: "missing".into(),
             expected: "file exists".into(),
-            fix: Some(format!("cp {} .env", example_name)),
+            fix: Some(FixAction::safe(
+                format!("cp {} .env", example_name),
+                &ctx.dir,
+            )),
             details: vec![],
         });
         return results;
@@ -49,6 +80,7 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
     if missing.is_empty() {
         results.push(CheckResult {
             category: "Environment".into(),
+            package: None,
             name: ".env".into(),
             status: Status::Pass,
             found: format!("{} keys", ctx.env.actual_keys.len()),
@@ -59,6 +91,7 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
     } else {
         results.push(CheckResult {
             category: "Environment".into(),
+            package: None,
             name: ".env".into(),
             status: Status::Fail,
             found: format!(
@@ -67,7 +100,7 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
                 if missing.len() == 1 { "" } else { "s" }
             ),
             expected: format!("{} required keys", expected_keys.len()),
-            fix: Some("add missing keys to .env".into()),
+            fix: Some(FixAction::manual("add missing keys to .env")),
             details: missing.iter().map(|k| format!("{} — missing", k)).collect(),
         });
     }
@@ -0,0 +1,178 @@
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
+use crate::config::VitalsConfig;
+use crate::detect::{LockfileInfo, ProjectContext};
+use crate::platform::Platform;
+use crate::version;
+
+pub struct LockfileCheck;
+
+impl Check for LockfileCheck {
+    fn id(&self) -> &'static str {
+        "lockfiles"
+    }
+
+    fn category(&self) -> &'static str {
+        "Lockfiles"
+    }
+
+    fn description(&self) -> &'static str {
+        "Lockfiles exist, cover every manifest dependency, and match the package manager"
+    }
+
+    fn applies(&self, ctx: &ProjectContext, _config: &VitalsConfig) -> bool {
+        !ctx.lockfiles.is_empty()
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        _config: &VitalsConfig,
+        _platform: &Platform,
+    ) -> Vec<CheckResult> {
+        ctx.lockfiles.iter().flat_map(check).collect()
+    }
+}
+
+pub fn check(lock: &LockfileInfo) -> Vec<CheckResult> {
+    let result = |name: &str, status: Status, found: String, expected: String| CheckResult {
+        category: "Lockfiles".into(),
+        package: None,
+        name: name.to_string(),
+        status,
+        found,
+        expected,
+        fix: None,
+        details: vec![],
+    };
+    let mut results = Vec::new();
+
+    if !lock.exists {
+        let mut r = result(
+            &lock.lockfile,
+            Status::Warn,
+            "missing".into(),
+            format!("generated from {}", lock.manifest),
+        );
+        r.fix = Some(FixAction::confirm(&lock.lock_cmd));
+        results.push(r);
+    } else {
+        let drift = drift(lock);
+        let expected = format!("matches {}", lock.manifest);
+        if drift.is_empty() {
+            results.push(result(
+                &lock.lockfile,
+                Status::Pass,
+                "up to date".into(),
+                expected,
+            ));
+        } else {
+            let mut r = result(&lock.lockfile, Status::Fail, "out of sync".into(), expected);
+            r.fix = Some(FixAction::confirm(&lock.lock_cmd));
+            r.details = drift;
+            results.push(r);
+        }
+    }
+
+    for stray in &lock.stray {
+        let mut r = result(
+            stray,
+            Status::Warn,
+            "present".into(),
+            format!("only {} ({} project)", lock.lockfile, lock.manager),
+        );
+        r.fix = Some(FixAction::manual(format!(
+            "remove {} ({} uses {})",
+            stray, lock.manager, lock.lockfile
+        )));
+        results.push(r);
+    }
+    results
+}
+
+/// Declared dependencies the lockfile doesn't have, or has at a version
+/// outside the declared range.
+fn drift(lock: &LockfileInfo) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (name, req) in &lock.declared {
+        let versions: Vec<&str> = lock
+            .locked
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+            .collect();
+        if versions.is_empty() {
+            lines.push(format!("{} is not in {}", name, lock.lockfile));
+            continue;
+        }
+        let Some(req) = req else {
+            continue;
+        };
+        if !versions.iter().any(|v| satisfies(v, req)) {
+            lines.push(format!(
+                "{} {} is locked at {}",
+                name,
+                req,
+                versions.join(", ")
+            ));
+        }
+    }
+    lines
+}
+
+/// Versions that don't parse ("1.0.0rc1", links without a version) can't be
+/// judged and count as satisfying.
+fn satisfies(found: &str, req: &str) -> bool {
+    found.is_empty()
+        || semver::Version::parse(&version::normalize_version(found)).is_err()
+        || version::meets_requirement(found, req)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockfile_drift() {
+        let mut lock = LockfileInfo {
+            manager: "pnpm".into(),
+            manifest: "package.json".into(),
+            lockfile: "pnpm-lock.yaml".into(),
+            exists: true,
+            declared: vec![
+                ("react".into(), Some("^18.3.0".into())),
+                ("zod".into(), Some("^3.22.0".into())),
+                ("local-lib".into(), None),
+            ],
+            locked: vec![
+                ("react".into(), "18.2.0".into()),
+                ("local-lib".into(), String::new()),
+            ],
+            lock_cmd: "pnpm install --lockfile-only".into(),
+            stray: vec!["package-lock.json".into()],
+        };
+        let results = check(&lock);
+        assert_eq!(results[0].status, Status::Fail);
+        assert_eq!(
+            results[0].details,
+            vec![
+                "react ^18.3.0 is locked at 18.2.0",
+                "zod is not in pnpm-lock.yaml",
+            ]
+        );
+        assert_eq!(
+            (results[1].name.as_str(), &results[1].status),
+            ("package-lock.json", &Status::Warn)
+        );
+
+        lock.locked = vec![
+            ("react".into(), "18.3.1".into()),
+            ("zod".into(), "3.23.8".into()),
+            ("local-lib".into(), String::new()),
+        ];
+        lock.stray.clear();
+        let results = check(&lock);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].status, Status::Pass);
+    }
+}
@@ -1,42 +1,352 @@
+pub mod containers;
 pub mod custom;
 pub mod deps;
 pub mod env;
+pub mod lockfile;
 pub mod port;
+pub mod probe;
 pub mod runtime;
 pub mod service;
+pub mod wrapper;
 
-use crate::check::CheckResult;
-use crate::config::VitalsConfig;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+
+use crate::check::{CheckResult, FixAction, Status};
+use crate::config::{self, VitalsConfig};
 use crate::detect::ProjectContext;
+use crate::parallel;
 use crate::platform::Platform;
+use crate::workspace;
+
+/// A pluggable health check.
+///
+/// Built-in checks live in the submodules of this module; crates embedding
+/// vitals as a library can implement this trait and add their own checks
+/// with [`Registry::register`].
+pub trait Check: Send + Sync {
+    /// Stable identifier used by `--skip` / `--only` (e.g., "runtime").
+    fn id(&self) -> &'static str;
+
+    /// Category heading results are grouped under (e.g., "Runtime").
+    fn category(&self) -> &'static str;
+
+    /// One-line description shown by `--list-checks`.
+    fn description(&self) -> &'static str {
+        ""
+    }
+
+    /// Whether this check has anything to do for the given project.
+    fn applies(&self, _ctx: &ProjectContext, _config: &VitalsConfig) -> bool {
+        true
+    }
+
+    /// How long the check may run before it is reported as timed out.
+    fn timeout(&self, config: &VitalsConfig) -> Duration {
+        config.checks.timeout_for(self.id())
+    }
+
+    /// Run the check.
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        platform: &Platform,
+    ) -> Vec<CheckResult>;
+}
+
+/// Which checks to run, by id.
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    /// Check ids to leave out.
+    pub skip: Vec<String>,
+    /// If non-empty, run only these check ids.
+    pub only: Vec<String>,
+}
+
+impl Selection {
+    /// Whether the check with this id should run.
+    pub fn includes(&self, id: &str) -> bool {
+        if self.skip.iter().any(|s| s == id) {
+            return false;
+        }
+        self.only.is_empty() || self.only.iter().any(|s| s == id)
+    }
+}
+
+/// Ordered collection of checks. Results are reported in registration order.
+pub struct Registry {
+    checks: Vec<Arc<dyn Check>>,
+}
+
+impl Registry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Registry { checks: Vec::new() }
+    }
+
+    /// A registry with all built-in checks.
+    pub fn builtin() -> Self {
+        let mut registry = Registry::new();
+        registry.register(runtime::RuntimeCheck);
+        registry.register(deps::DepsCheck);
+        registry.register(lockfile::LockfileCheck);
+        registry.register(wrapper::WrapperCheck);
+        registry.register(service::ServiceCheck);
+        registry.register(containers::ContainersCheck);
+        registry.register(port::PortCheck);
+        registry.register(env::EnvCheck);
+        registry.register(custom::CommandsCheck);
+        registry
+    }
+
+    /// Add a check. Replaces any existing check with the same id.
+    pub fn register(&mut self, check: impl Check + 'static) {
+        match self.checks.iter().position(|c| c.id() == check.id()) {
+            Some(i) => self.checks[i] = Arc::new(check),
+            None => self.checks.push(Arc::new(check)),
+        }
+    }
+
+    /// All registered checks, in run order.
+    pub fn checks(&self) -> impl Iterator<Item = &dyn Check> {
+        self.checks.iter().map(|c| c.as_ref())
+    }
+
+    /// Error out on `--skip` / `--only` ids that no registered check has.
+    pub fn validate(&self, selection: &Selection) -> Result<()> {
+        for id in selection.skip.iter().chain(&selection.only) {
+            if !self.checks.iter().any(|c| c.id() == id) {
+                let known: Vec<&str> = self.checks.iter().map(|c| c.id()).collect();
+                bail!("unknown check `{}` (available: {})", id, known.join(", "));
+            }
+        }
+        Ok(())
+    }
+
+    /// Run every selected, applicable check.
+    ///
+    /// Checks run concurrently on a bounded worker pool (`[checks] workers`),
+    /// each with its own timeout. Results keep registration order.
+    pub fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        platform: &Platform,
+        selection: &Selection,
+    ) -> Vec<CheckResult> {
+        let selected: Vec<Arc<dyn Check>> = self
+            .checks
+            .iter()
+            .filter(|c| selection.includes(c.id()) && c.applies(ctx, config))
+            .cloned()
+            .collect();
 
-/// Run all enabled checks and return results grouped by category.
+        // Timed-out checks keep running detached, so they need owned inputs.
+        let shared = Arc::new((ctx.clone(), config.clone(), platform.clone()));
+        let workers = config
+            .checks
+            .workers
+            .unwrap_or_else(parallel::default_workers);
+
+        parallel::map(selected, workers, |check| {
+            let timeout = check.timeout(config);
+            let job = Arc::clone(&check);
+            let inputs = Arc::clone(&shared);
+            parallel::with_timeout(timeout, move || {
+                let (ctx, config, platform) = &*inputs;
+                job.run(ctx, config, platform)
+            })
+            .unwrap_or_else(|| vec![timed_out(check.as_ref(), timeout)])
+        })
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Run the checks for the root project and every workspace member.
+    ///
+    /// Member results are tagged with the member name; machine-wide findings
+    /// reported identically by several packages are merged (see
+    /// [`workspace::merge`]). Members use their own `.vitals.toml` if they
+    /// have one, otherwise the root's `[require]`, `[services]` and
+    /// `[checks]` settings. A member `.vitals.toml` that fails to parse is an
+    /// error, like the root's.
+    pub fn run_workspace(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        platform: &Platform,
+        selection: &Selection,
+    ) -> Result<Vec<CheckResult>> {
+        let mut results = self.run(ctx, config, platform, selection);
+        if ctx.members.is_empty() {
+            return Ok(results);
+        }
+
+        for member in &ctx.members {
+            let member_config = if member.project.dir.join(".vitals.toml").exists() {
+                config::load(&member.project.dir)
+                    .with_context(|| format!("invalid {}/.vitals.toml", member.name))?
+            } else {
+                VitalsConfig {
+                    require: config.require.clone(),
+                    services: config.services.clone(),
+                    checks: config.checks.clone(),
+                    ..Default::default()
+                }
+            };
+            for mut result in self.run(&member.project, &member_config, platform, selection) {
+                result.package = Some(member.name.clone());
+                if let Some(fix) = &mut result.fix {
+                    fix.cwd.get_or_insert_with(|| member.project.dir.clone());
+                }
+                results.push(result);
+            }
+        }
+
+        let names: Vec<String> = ctx.members.iter().map(|m| m.name.clone()).collect();
+        Ok(workspace::merge(results, &names))
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry::builtin()
+    }
+}
+
+/// Result reported for a check that did not finish within its timeout.
+fn timed_out(check: &dyn Check, timeout: Duration) -> CheckResult {
+    CheckResult {
+        category: check.category().into(),
+        package: None,
+        name: check.id().into(),
+        status: Status::Fail,
+        found: format!("timed out after {}s", timeout.as_secs()),
+        expected: format!("completes within {}s", timeout.as_secs()),
+        fix: Some(FixAction::manual(format!(
+            "raise [checks.timeouts] {} in .vitals.toml",
+            check.id()
+        ))),
+        details: vec![],
+    }
+}
+
+/// Run all selected built-in checks and return results grouped by category.
 pub fn run_all(
     ctx: &ProjectContext,
     config: &VitalsConfig,
     platform: &Platform,
-    skip: &[String],
+    selection: &Selection,
 ) -> Vec<CheckResult> {
-    let mut results = Vec::new();
+    Registry::builtin().run(ctx, config, platform, selection)
+}
 
-    if !skip.contains(&"runtime".to_string()) {
-        results.extend(runtime::check(ctx, config, platform));
-    }
-    if !skip.contains(&"deps".to_string()) {
-        results.extend(deps::check(ctx));
-    }
-    if !skip.contains(&"services".to_string()) {
-        results.extend(service::check(ctx, config, platform));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Custom;
+
+    impl Check for Custom {
+        fn id(&self) -> &'static str {
+            "custom-check"
+        }
+        fn category(&self) -> &'static str {
+            "Custom"
+        }
+        fn run(&self, _: &ProjectContext, _: &VitalsConfig, _: &Platform) -> Vec<CheckResult> {
+            vec![CheckResult {
+                category: self.category().into(),
+                package: None,
+                name: "hello".into(),
+                status: Status::Pass,
+                found: "ok".into(),
+                expected: "ok".into(),
+                fix: None,
+                details: vec![],
+            }]
+        }
     }
-    if !skip.contains(&"ports".to_string()) {
-        results.extend(port::check(ctx, config));
+
+    struct Slow;
+
+    impl Check for Slow {
+        fn id(&self) -> &'static str {
+            "slow"
+        }
+        fn category(&self) -> &'static str {
+            "Custom"
+        }
+        fn timeout(&self, _: &VitalsConfig) -> Duration {
+            Duration::from_millis(50)
+        }
+        fn run(&self, _: &ProjectContext, _: &VitalsConfig, _: &Platform) -> Vec<CheckResult> {
+            std::thread::sleep(Duration::from_secs(2));
+            vec![]
+        }
     }
-    if !skip.contains(&"env".to_string()) {
-        results.extend(env::check(ctx, config));
+
+    #[test]
+    fn test_selection() {
+        let sel = Selection {
+            skip: vec!["ports".into()],
+            only: vec![],
+        };
+        assert!(sel.includes("runtime"));
+        assert!(!sel.includes("ports"));
+
+        let sel = Selection {
+            skip: vec![],
+            only: vec!["env".into()],
+        };
+        assert!(sel.includes("env"));
+        assert!(!sel.includes("runtime"));
     }
-    if !skip.contains(&"commands".to_string()) {
-        results.extend(custom::check(ctx, config));
+
+    #[test]
+    fn test_registry_custom_check() {
+        let mut registry = Registry::builtin();
+        registry.register(Custom);
+
+        let only = Selection {
+            skip: vec![],
+            only: vec!["custom-check".into()],
+        };
+        assert!(registry.validate(&only).is_ok());
+
+        let ctx = crate::detect::scan(std::path::Path::new("/nonexistent")).unwrap();
+        let results = registry.run(&ctx, &VitalsConfig::default(), &Platform::Unknown, &only);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "hello");
+
+        let bogus = Selection {
+            skip: vec!["nope".into()],
+            only: vec![],
+        };
+        assert!(registry.validate(&bogus).is_err());
     }
 
-    results
+    #[test]
+    fn test_registry_timeout_keeps_order() {
+        let mut registry = Registry::new();
+        registry.register(Slow);
+        registry.register(Custom);
+
+        let ctx = crate::detect::scan(std::path::Path::new("/nonexistent")).unwrap();
+        let results = registry.run(
+            &ctx,
+            &VitalsConfig::default(),
+            &Platform::Unknown,
+            &Selection::default(),
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "slow");
+        assert_eq!(results[0].status, Status::Fail);
+        assert!(results[0].found.starts_with("timed out"));
+        assert_eq!(results[1].name, "hello");
+    }
 }
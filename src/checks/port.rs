@@ -1,10 +1,37 @@
 use std::net::TcpListener;
 
-use crate::check::{CheckResult, Status};
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
 use crate::config::VitalsConfig;
 use crate::detect::ProjectContext;
+use crate::platform::Platform;
 use crate::version;
 
+pub struct PortCheck;
+
+impl Check for PortCheck {
+    fn id(&self) -> &'static str {
+        "ports"
+    }
+
+    fn category(&self) -> &'static str {
+        "Ports"
+    }
+
+    fn description(&self) -> &'static str {
+        "Required ports are available"
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        _platform: &Platform,
+    ) -> Vec<CheckResult> {
+        check(ctx, config)
+    }
+}
+
 pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
     let mut results = Vec::new();
 
@@ -22,6 +49,7 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
         if is_port_available(*port) {
             results.push(CheckResult {
                 category: "Ports".into(),
+                package: None,
                 name: format!(":{}", port),
                 status: Status::Pass,
                 found: "available".into(),
@@ -41,11 +69,17 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig) -> Vec<CheckResult> {
                     p.split("PID ")
                         .nth(1)
                         .and_then(|s| s.split(')').next())
-                        .map(|pid| format!("kill {}", pid))
+                        .map(|pid| FixAction::confirm(format!("kill {}", pid)))
                 })
-                .or_else(|| Some(format!("lsof -i :{} to find the process", port)));
+                .or_else(|| {
+                    Some(FixAction::manual(format!(
+                        "lsof -i :{} to find the process",
+                        port
+                    )))
+                });
             results.push(CheckResult {
                 category: "Ports".into(),
+                package: None,
                 name: format!(":{}", port),
                 status: Status::Fail,
                 found,
@@ -0,0 +1,947 @@
+//! Lightweight protocol handshakes for well-known services.
+//!
+//! Each probe speaks just enough of the wire protocol to tell whether the
+//! server is actually healthy (not merely accepting TCP connections) and,
+//! where the protocol exposes it, which version is running.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::version;
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Connection details for a probe.
+#[derive(Debug, Clone, Default)]
+pub struct Target<'a> {
+    pub host: &'a str,
+    pub port: u16,
+    pub user: Option<&'a str>,
+    pub password: Option<&'a str>,
+    pub database: Option<&'a str>,
+}
+
+/// Outcome of probing a service.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Health {
+    /// The service completed its handshake.
+    Healthy {
+        version: Option<String>,
+        /// Extra context (e.g., "scram auth not verified").
+        note: Option<String>,
+    },
+    /// Something answered, but the service reported a problem.
+    Unhealthy(String),
+    /// Nothing is listening.
+    Unreachable,
+}
+
+impl Health {
+    fn up(version: Option<String>) -> Self {
+        Health::Healthy {
+            version,
+            note: None,
+        }
+    }
+
+    fn up_with_note(version: Option<String>, note: impl Into<String>) -> Self {
+        Health::Healthy {
+            version,
+            note: Some(note.into()),
+        }
+    }
+}
+
+/// Probe a service of the given kind (e.g., "redis", "postgres").
+///
+/// Kinds without a protocol probe fall back to a plain TCP connect.
+pub fn probe(kind: &str, target: &Target) -> Health {
+    let Some(mut stream) = connect(target.host, target.port) else {
+        return Health::Unreachable;
+    };
+
+    let result = match kind {
+        "redis" => redis(&mut stream, target),
+        "postgres" => postgres(&mut stream, target),
+        "mysql" => mysql(&mut stream),
+        "mongo" => mongo(&mut stream),
+        "rabbitmq" => amqp(&mut stream),
+        "memcached" => memcached(&mut stream),
+        "elasticsearch" | "minio" => http(&mut stream, target),
+        _ => return Health::up(None),
+    };
+
+    result.unwrap_or_else(|e| Health::Unhealthy(format!("no {} handshake ({})", kind, e)))
+}
+
+fn connect(host: &str, port: u16) -> Option<TcpStream> {
+    let addr = (host, port).to_socket_addrs().ok()?.next()?;
+    let stream = TcpStream::connect_timeout(&addr, TIMEOUT).ok()?;
+    stream.set_read_timeout(Some(TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(TIMEOUT)).ok()?;
+    Some(stream)
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+// ── Redis ───────────────────────────────────────────────────────────────────
+
+fn redis(stream: &mut TcpStream, target: &Target) -> io::Result<Health> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    if let Some(password) = target.password {
+        let mut args = vec!["AUTH"];
+        args.extend(target.user);
+        args.push(password);
+        stream.write_all(&resp_command(&args))?;
+        if let Resp::Error(e) = read_resp(&mut reader)? {
+            return Ok(Health::Unhealthy(format!("AUTH rejected: {}", e)));
+        }
+    }
+
+    stream.write_all(&resp_command(&["PING"]))?;
+    match read_resp(&mut reader)? {
+        Resp::Simple(s) if s == "PONG" => {}
+        Resp::Error(e) => return Ok(Health::Unhealthy(e)),
+        other => return Err(invalid(&format!("unexpected PING reply {:?}", other))),
+    }
+
+    stream.write_all(&resp_command(&["INFO", "server"]))?;
+    let version = match read_resp(&mut reader)? {
+        Resp::Bulk(info) => info
+            .lines()
+            .find_map(|l| l.strip_prefix("redis_version:"))
+            .map(|v| v.trim().to_string()),
+        _ => None,
+    };
+    Ok(Health::up(version))
+}
+
+#[derive(Debug)]
+enum Resp {
+    Simple(String),
+    Error(String),
+    Bulk(String),
+    Other,
+}
+
+fn resp_command(args: &[&str]) -> Vec<u8> {
+    let mut out = format!("*{}\r\n", args.len());
+    for arg in args {
+        out.push_str(&format!("${}\r\n{}\r\n", arg.len(), arg));
+    }
+    out.into_bytes()
+}
+
+fn read_resp(reader: &mut impl BufRead) -> io::Result<Resp> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(invalid("connection closed"));
+    }
+    let line = line.trim_end();
+    let (kind, rest) = line.split_at(1.min(line.len()));
+    Ok(match kind {
+        "+" => Resp::Simple(rest.to_string()),
+        "-" => Resp::Error(rest.to_string()),
+        "$" => {
+            let len: i64 = rest.parse().map_err(|_| invalid("bad bulk length"))?;
+            if len < 0 {
+                return Ok(Resp::Other);
+            }
+            let mut buf = vec![0u8; len as usize + 2];
+            reader.read_exact(&mut buf)?;
+            buf.truncate(len as usize);
+            Resp::Bulk(String::from_utf8_lossy(&buf).to_string())
+        }
+        ":" | "*" => Resp::Other,
+        _ => return Err(invalid("not a RESP reply")),
+    })
+}
+
+// ── PostgreSQL ──────────────────────────────────────────────────────────────
+
+const PG_SSL_REQUEST: i32 = 80877103;
+const PG_PROTOCOL_3: i32 = 196608;
+
+fn postgres(stream: &mut TcpStream, target: &Target) -> io::Result<Health> {
+    // SSLRequest: any real Postgres answers with a single 'S' or 'N'.
+    let mut req = Vec::new();
+    req.extend(8i32.to_be_bytes());
+    req.extend(PG_SSL_REQUEST.to_be_bytes());
+    stream.write_all(&req)?;
+
+    let mut answer = [0u8; 1];
+    stream.read_exact(&mut answer)?;
+    match answer[0] {
+        b'S' => {
+            // We don't speak TLS; the server is up but we can't go further.
+            return Ok(Health::up_with_note(
+                None,
+                "ssl required, auth not verified",
+            ));
+        }
+        b'N' => {}
+        _ => return Err(invalid("unexpected SSLRequest reply")),
+    }
+
+    let credentials_given = target.user.is_some();
+    let user = target.user.unwrap_or("postgres");
+    let database = target.database.unwrap_or(user);
+
+    let mut body = Vec::new();
+    body.extend(PG_PROTOCOL_3.to_be_bytes());
+    for (k, v) in [("user", user), ("database", database)] {
+        body.extend(k.as_bytes());
+        body.push(0);
+        body.extend(v.as_bytes());
+        body.push(0);
+    }
+    body.push(0);
+    let mut startup = ((body.len() + 4) as i32).to_be_bytes().to_vec();
+    startup.extend(body);
+    stream.write_all(&startup)?;
+
+    let mut server_version = None;
+    loop {
+        let (tag, body) = pg_read_message(stream)?;
+        match tag {
+            b'R' => {
+                let code = i32::from_be_bytes(
+                    body.get(..4)
+                        .ok_or(invalid("short auth"))?
+                        .try_into()
+                        .unwrap(),
+                );
+                match code {
+                    0 => {} // AuthenticationOk
+                    3 => match target.password {
+                        Some(password) => {
+                            let mut msg = vec![b'p'];
+                            msg.extend(((password.len() + 5) as i32).to_be_bytes());
+                            msg.extend(password.as_bytes());
+                            msg.push(0);
+                            stream.write_all(&msg)?;
+                        }
+                        None => return Ok(Health::up_with_note(None, "password required")),
+                    },
+                    5 => return Ok(Health::up_with_note(None, "md5 auth not verified")),
+                    10 => return Ok(Health::up_with_note(None, "scram auth not verified")),
+                    _ => return Ok(Health::up_with_note(None, "auth not verified")),
+                }
+            }
+            b'S' => {
+                let mut parts = body.split(|b| *b == 0);
+                if parts.next() == Some(b"server_version") {
+                    server_version = parts
+                        .next()
+                        .and_then(|v| version::extract_version(&String::from_utf8_lossy(v)));
+                }
+            }
+            b'E' => {
+                let (code, message) = pg_error_fields(&body);
+                // Credential / database errors only matter if the user told us
+                // which credentials to use; otherwise we guessed "postgres".
+                let auth_error = code.starts_with("28") || code == "3D000";
+                if auth_error && !credentials_given {
+                    return Ok(Health::up_with_note(None, "auth not verified"));
+                }
+                return Ok(Health::Unhealthy(message));
+            }
+            b'Z' => {
+                // ReadyForQuery — fully logged in. Say goodbye politely.
+                let _ = stream.write_all(&[b'X', 0, 0, 0, 4]);
+                return Ok(Health::up(server_version));
+            }
+            _ => {} // BackendKeyData, NoticeResponse, ...
+        }
+    }
+}
+
+fn pg_read_message(stream: &mut impl Read) -> io::Result<(u8, Vec<u8>)> {
+    let mut header = [0u8; 5];
+    stream.read_exact(&mut header)?;
+    let len = i32::from_be_bytes(header[1..5].try_into().unwrap());
+    if !(4..=1 << 20).contains(&len) {
+        return Err(invalid("bad message length"));
+    }
+    let mut body = vec![0u8; len as usize - 4];
+    stream.read_exact(&mut body)?;
+    Ok((header[0], body))
+}
+
+fn pg_error_fields(body: &[u8]) -> (String, String) {
+    let mut code = String::new();
+    let mut message = String::new();
+    for field in body.split(|b| *b == 0) {
+        let Some((&kind, value)) = field.split_first() else {
+            continue;
+        };
+        let value = String::from_utf8_lossy(value).to_string();
+        match kind {
+            b'C' => code = value,
+            b'M' => message = value,
+            _ => {}
+        }
+    }
+    (code, message)
+}
+
+// ── MySQL / MariaDB ─────────────────────────────────────────────────────────
+
+fn mysql(stream: &mut TcpStream) -> io::Result<Health> {
+    // The server speaks first with its initial handshake packet.
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header)?;
+    let len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload)?;
+
+    match payload.first() {
+        Some(10) => {
+            let end = payload[1..]
+                .iter()
+                .position(|b| *b == 0)
+                .unwrap_or(payload.len() - 1);
+            let server = String::from_utf8_lossy(&payload[1..1 + end]).to_string();
+            Ok(Health::up(
+                version::extract_version(&server).or(Some(server)),
+            ))
+        }
+        Some(0xff) => {
+            // ERR packet: 0xff, error code (2), optional '#' + SQLSTATE, message.
+            let mut msg = payload.get(3..).unwrap_or_default();
+            if msg.first() == Some(&b'#') {
+                msg = msg.get(6..).unwrap_or_default();
+            }
+            Ok(Health::Unhealthy(String::from_utf8_lossy(msg).to_string()))
+        }
+        _ => Err(invalid("not a MySQL handshake")),
+    }
+}
+
+// ── MongoDB ─────────────────────────────────────────────────────────────────
+
+const OP_MSG: i32 = 2013;
+
+fn mongo(stream: &mut TcpStream) -> io::Result<Health> {
+    let mut reply = mongo_command(stream, 1, "hello")?;
+    if !bson_ok(&reply) {
+        // Servers older than 4.4.2 only know the legacy name.
+        reply = mongo_command(stream, 2, "isMaster")?;
+    }
+    if !bson_ok(&reply) {
+        let msg = bson_get(&reply, "errmsg")
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_else(|| "hello failed".into());
+        return Ok(Health::Unhealthy(msg));
+    }
+
+    let version = mongo_command(stream, 3, "buildInfo")
+        .ok()
+        .and_then(|doc| bson_get(&doc, "version"))
+        .and_then(|v| v.as_str().map(str::to_string));
+    Ok(Health::up(version))
+}
+
+fn mongo_command(stream: &mut TcpStream, request_id: i32, command: &str) -> io::Result<Vec<u8>> {
+    let doc = bson_document(&[
+        (command, Bson::Int32(1)),
+        ("$db", Bson::String("admin".into())),
+    ]);
+
+    let mut msg = Vec::new();
+    msg.extend(0i32.to_le_bytes()); // length, patched below
+    msg.extend(request_id.to_le_bytes());
+    msg.extend(0i32.to_le_bytes()); // responseTo
+    msg.extend(OP_MSG.to_le_bytes());
+    msg.extend(0u32.to_le_bytes()); // flagBits
+    msg.push(0); // section kind 0: body
+    msg.extend(doc);
+    let len = (msg.len() as i32).to_le_bytes();
+    msg[..4].copy_from_slice(&len);
+    stream.write_all(&msg)?;
+
+    let mut header = [0u8; 16];
+    stream.read_exact(&mut header)?;
+    let len = i32::from_le_bytes(header[..4].try_into().unwrap());
+    let opcode = i32::from_le_bytes(header[12..16].try_into().unwrap());
+    if opcode != OP_MSG || !(21..=48_000_000).contains(&len) {
+        return Err(invalid("not an OP_MSG reply"));
+    }
+    let mut body = vec![0u8; len as usize - 16];
+    stream.read_exact(&mut body)?;
+    // flagBits (4) + section kind (1) precede the document.
+    Ok(body.get(5..).unwrap_or_default().to_vec())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Bson {
+    Double(f64),
+    String(String),
+    Bool(bool),
+    Int32(i32),
+    Int64(i64),
+    Other,
+}
+
+impl Bson {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Bson::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Bson::Double(d) => Some(*d),
+            Bson::Int32(i) => Some(*i as f64),
+            Bson::Int64(i) => Some(*i as f64),
+            Bson::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+            _ => None,
+        }
+    }
+}
+
+fn bson_document(fields: &[(&str, Bson)]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (key, value) in fields {
+        let (tag, bytes) = match value {
+            Bson::Int32(i) => (0x10, i.to_le_bytes().to_vec()),
+            Bson::String(s) => {
+                let mut b = ((s.len() + 1) as i32).to_le_bytes().to_vec();
+                b.extend(s.as_bytes());
+                b.push(0);
+                (0x02, b)
+            }
+            _ => continue,
+        };
+        body.push(tag);
+        body.extend(key.as_bytes());
+        body.push(0);
+        body.extend(bytes);
+    }
+    let mut doc = ((body.len() + 5) as i32).to_le_bytes().to_vec();
+    doc.extend(body);
+    doc.push(0);
+    doc
+}
+
+/// Look up a top-level field in a BSON document.
+fn bson_get(doc: &[u8], wanted: &str) -> Option<Bson> {
+    let mut i = 4;
+    while i < doc.len() {
+        let tag = doc[i];
+        if tag == 0 {
+            return None;
+        }
+        i += 1;
+        let key_end = i + doc[i..].iter().position(|b| *b == 0)?;
+        let key = std::str::from_utf8(&doc[i..key_end]).ok()?;
+        i = key_end + 1;
+
+        let read_i32 = |at: usize| -> Option<i32> {
+            Some(i32::from_le_bytes(doc.get(at..at + 4)?.try_into().ok()?))
+        };
+        let (value, size) = match tag {
+            0x01 => (
+                Bson::Double(f64::from_le_bytes(doc.get(i..i + 8)?.try_into().ok()?)),
+                8,
+            ),
+            0x02 => {
+                let len = read_i32(i)? as usize;
+                let s = doc.get(i + 4..i + 4 + len.saturating_sub(1))?;
+                (
+                    Bson::String(String::from_utf8_lossy(s).to_string()),
+                    4 + len,
+                )
+            }
+            0x03 | 0x04 => (Bson::Other, read_i32(i)? as usize),
+            0x05 => (Bson::Other, 5 + read_i32(i)? as usize),
+            0x07 => (Bson::Other, 12),
+            0x08 => (Bson::Bool(*doc.get(i)? != 0), 1),
+            0x09 | 0x11 => (Bson::Other, 8),
+            0x0A => (Bson::Other, 0),
+            0x10 => (Bson::Int32(read_i32(i)?), 4),
+            0x12 => (
+                Bson::Int64(i64::from_le_bytes(doc.get(i..i + 8)?.try_into().ok()?)),
+                8,
+            ),
+            0x13 => (Bson::Other, 16),
+            _ => return None,
+        };
+        if key == wanted {
+            return Some(value);
+        }
+        i += size;
+    }
+    None
+}
+
+fn bson_ok(doc: &[u8]) -> bool {
+    bson_get(doc, "ok").and_then(|v| v.as_f64()) == Some(1.0)
+}
+
+// ── RabbitMQ (AMQP 0-9-1) ───────────────────────────────────────────────────
+
+fn amqp(stream: &mut TcpStream) -> io::Result<Health> {
+    stream.write_all(b"AMQP\x00\x00\x09\x01")?;
+
+    let mut header = [0u8; 7];
+    stream.read_exact(&mut header)?;
+    if &header[..4] == b"AMQP" {
+        // Server rejected our protocol version and sent the one it wants.
+        let mut rest = [0u8; 1];
+        stream.read_exact(&mut rest)?;
+        return Ok(Health::Unhealthy(format!(
+            "server requires AMQP {}-{}-{}",
+            header[5], header[6], rest[0]
+        )));
+    }
+
+    // Method frame on channel 0 carrying Connection.Start (class 10, method 10).
+    let size = u32::from_be_bytes(header[3..7].try_into().unwrap()) as usize;
+    if header[0] != 1 || size > 1 << 20 {
+        return Err(invalid("not an AMQP method frame"));
+    }
+    let mut payload = vec![0u8; size + 1];
+    stream.read_exact(&mut payload)?;
+    if payload.get(..4) != Some(&[0, 10, 0, 10]) {
+        return Err(invalid("expected Connection.Start"));
+    }
+
+    // class(2) method(2) major(1) minor(1), then the server-properties table.
+    let version = amqp_table_string(payload.get(6..).unwrap_or_default(), "version");
+    Ok(Health::up(version))
+}
+
+/// Find a long-string value in an AMQP field table.
+fn amqp_table_string(data: &[u8], wanted: &str) -> Option<String> {
+    let len = u32::from_be_bytes(data.get(..4)?.try_into().ok()?) as usize;
+    let table = data.get(4..4 + len)?;
+    let mut i = 0;
+    while i < table.len() {
+        let key_len = *table.get(i)? as usize;
+        let key = table.get(i + 1..i + 1 + key_len)?;
+        i += 1 + key_len;
+        let kind = *table.get(i)?;
+        i += 1;
+        let read_u32 = |at: usize| -> Option<usize> {
+            Some(u32::from_be_bytes(table.get(at..at + 4)?.try_into().ok()?) as usize)
+        };
+        let size = match kind {
+            b'S' | b'x' => {
+                let n = read_u32(i)?;
+                if key == wanted.as_bytes() && kind == b'S' {
+                    let value = table.get(i + 4..i + 4 + n)?;
+                    return Some(String::from_utf8_lossy(value).to_string());
+                }
+                4 + n
+            }
+            b'F' | b'A' => 4 + read_u32(i)?,
+            b't' | b'b' | b'B' => 1,
+            b's' | b'u' => 2,
+            b'I' | b'i' | b'f' => 4,
+            b'l' | b'd' | b'T' => 8,
+            b'D' => 5,
+            b'V' => 0,
+            _ => return None,
+        };
+        i += size;
+    }
+    None
+}
+
+// ── Memcached ───────────────────────────────────────────────────────────────
+
+fn memcached(stream: &mut TcpStream) -> io::Result<Health> {
+    stream.write_all(b"version\r\n")?;
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line)?;
+    match line.trim().strip_prefix("VERSION ") {
+        Some(v) => Ok(Health::up(Some(v.to_string()))),
+        None if line.starts_with("SERVER_ERROR") => Ok(Health::Unhealthy(line.trim().into())),
+        None => Err(invalid("unexpected reply to version")),
+    }
+}
+
+// ── HTTP (Elasticsearch, MinIO) ─────────────────────────────────────────────
+
+fn http(stream: &mut TcpStream, target: &Target) -> io::Result<Health> {
+    let mut request = format!(
+        "GET / HTTP/1.0\r\nHost: {}:{}\r\nUser-Agent: vitals\r\nAccept: application/json\r\n",
+        target.host, target.port
+    );
+    if let (Some(user), Some(password)) = (target.user, target.password) {
+        request.push_str(&format!(
+            "Authorization: Basic {}\r\n",
+            base64(format!("{}:{}", user, password).as_bytes())
+        ));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = Vec::new();
+    stream.take(64 * 1024).read_to_end(&mut response)?;
+    let response = String::from_utf8_lossy(&response);
+
+    let status: u16 = response
+        .lines()
+        .next()
+        .filter(|l| l.starts_with("HTTP/"))
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid("not an HTTP response"))?;
+
+    let body = response.split("\r\n\r\n").nth(1).unwrap_or_default();
+    // Elasticsearch: {"version": {"number": "8.12.0", ...}}
+    let version = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| v.pointer("/version/number")?.as_str().map(str::to_string));
+
+    match status {
+        401 | 403 if target.password.is_some() => Ok(Health::Unhealthy(format!(
+            "HTTP {}: credentials rejected",
+            status
+        ))),
+        401 | 403 => Ok(Health::up_with_note(version, "auth required")),
+        500.. => Ok(Health::Unhealthy(format!("HTTP {}", status))),
+        _ => Ok(Health::up(version)),
+    }
+}
+
+fn base64(input: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::new();
+    for chunk in input.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(TABLE[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Start a one-shot fake server and return its port.
+    fn serve(handler: impl FnOnce(TcpStream) + Send + 'static) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            if let Ok((stream, _)) = listener.accept() {
+                handler(stream);
+            }
+        });
+        port
+    }
+
+    fn target(port: u16) -> Target<'static> {
+        Target {
+            host: "127.0.0.1",
+            port,
+            ..Default::default()
+        }
+    }
+
+    fn read_some(stream: &mut TcpStream) -> Vec<u8> {
+        let mut buf = [0u8; 1024];
+        let n = stream.read(&mut buf).unwrap_or(0);
+        buf[..n].to_vec()
+    }
+
+    #[test]
+    fn test_redis_pong_and_version() {
+        let port = serve(|mut s| {
+            read_some(&mut s);
+            s.write_all(b"+PONG\r\n").unwrap();
+            read_some(&mut s);
+            let info = "# Server\r\nredis_version:7.2.4\r\n";
+            s.write_all(format!("${}\r\n{}\r\n", info.len(), info).as_bytes())
+                .unwrap();
+        });
+        assert_eq!(
+            probe("redis", &target(port)),
+            Health::up(Some("7.2.4".into()))
+        );
+    }
+
+    #[test]
+    fn test_redis_loading() {
+        let port = serve(|mut s| {
+            read_some(&mut s);
+            s.write_all(b"-LOADING Redis is loading the dataset in memory\r\n")
+                .unwrap();
+        });
+        assert_eq!(
+            probe("redis", &target(port)),
+            Health::Unhealthy("LOADING Redis is loading the dataset in memory".into())
+        );
+    }
+
+    #[test]
+    fn test_redis_auth_rejected() {
+        let port = serve(|mut s| {
+            let req = read_some(&mut s);
+            assert!(String::from_utf8_lossy(&req).contains("AUTH"));
+            s.write_all(b"-WRONGPASS invalid username-password pair\r\n")
+                .unwrap();
+        });
+        let t = Target {
+            password: Some("nope"),
+            ..target(port)
+        };
+        assert!(matches!(probe("redis", &t), Health::Unhealthy(m) if m.contains("WRONGPASS")));
+    }
+
+    fn pg_message(tag: u8, body: &[u8]) -> Vec<u8> {
+        let mut m = vec![tag];
+        m.extend(((body.len() + 4) as i32).to_be_bytes());
+        m.extend(body);
+        m
+    }
+
+    #[test]
+    fn test_postgres_trust_login() {
+        let port = serve(|mut s| {
+            let mut ssl = [0u8; 8];
+            s.read_exact(&mut ssl).unwrap();
+            assert_eq!(&ssl[4..], &PG_SSL_REQUEST.to_be_bytes());
+            s.write_all(b"N").unwrap();
+            read_some(&mut s);
+            s.write_all(&pg_message(b'R', &0i32.to_be_bytes())).unwrap();
+            s.write_all(&pg_message(b'S', b"server_version\x0016.2 (Debian)\x00"))
+                .unwrap();
+            s.write_all(&pg_message(b'Z', b"I")).unwrap();
+        });
+        assert_eq!(
+            probe("postgres", &target(port)),
+            Health::up(Some("16.2".into()))
+        );
+    }
+
+    #[test]
+    fn test_postgres_bad_password() {
+        let port = serve(|mut s| {
+            let mut ssl = [0u8; 8];
+            s.read_exact(&mut ssl).unwrap();
+            s.write_all(b"N").unwrap();
+            read_some(&mut s);
+            s.write_all(&pg_message(b'R', &3i32.to_be_bytes())).unwrap();
+            read_some(&mut s);
+            s.write_all(&pg_message(
+                b'E',
+                b"SFATAL\x00C28P01\x00Mpassword authentication failed for user \"app\"\x00\x00",
+            ))
+            .unwrap();
+        });
+        let t = Target {
+            user: Some("app"),
+            password: Some("wrong"),
+            ..target(port)
+        };
+        assert_eq!(
+            probe("postgres", &t),
+            Health::Unhealthy("password authentication failed for user \"app\"".into())
+        );
+    }
+
+    #[test]
+    fn test_postgres_starting_up() {
+        let port = serve(|mut s| {
+            let mut ssl = [0u8; 8];
+            s.read_exact(&mut ssl).unwrap();
+            s.write_all(b"N").unwrap();
+            read_some(&mut s);
+            s.write_all(&pg_message(
+                b'E',
+                b"SFATAL\x00C57P03\x00Mthe database system is starting up\x00\x00",
+            ))
+            .unwrap();
+        });
+        assert_eq!(
+            probe("postgres", &target(port)),
+            Health::Unhealthy("the database system is starting up".into())
+        );
+    }
+
+    #[test]
+    fn test_mysql_handshake() {
+        let port = serve(|mut s| {
+            let mut payload = vec![10u8];
+            payload.extend(b"8.0.36-0ubuntu0.22.04.1\x00");
+            payload.extend([0u8; 20]);
+            let mut packet = (payload.len() as u32).to_le_bytes()[..3].to_vec();
+            packet.push(0);
+            packet.extend(payload);
+            s.write_all(&packet).unwrap();
+        });
+        assert_eq!(
+            probe("mysql", &target(port)),
+            Health::up(Some("8.0.36".into()))
+        );
+    }
+
+    #[test]
+    fn test_mysql_error_packet() {
+        let port = serve(|mut s| {
+            let mut payload = vec![0xffu8, 0x10, 0x04];
+            payload.extend(b"Too many connections");
+            let mut packet = (payload.len() as u32).to_le_bytes()[..3].to_vec();
+            packet.push(0);
+            packet.extend(payload);
+            s.write_all(&packet).unwrap();
+        });
+        assert_eq!(
+            probe("mysql", &target(port)),
+            Health::Unhealthy("Too many connections".into())
+        );
+    }
+
+    fn op_msg_reply(doc: Vec<u8>) -> Vec<u8> {
+        let mut msg = Vec::new();
+        msg.extend(((doc.len() + 21) as i32).to_le_bytes());
+        msg.extend(0i32.to_le_bytes());
+        msg.extend(0i32.to_le_bytes());
+        msg.extend(OP_MSG.to_le_bytes());
+        msg.extend(0u32.to_le_bytes());
+        msg.push(0);
+        msg.extend(doc);
+        msg
+    }
+
+    #[test]
+    fn test_mongo_hello_and_build_info() {
+        let port = serve(|mut s| {
+            let req = read_some(&mut s);
+            assert!(req.windows(5).any(|w| w == b"hello"));
+            let mut ok = vec![0x01];
+            ok.extend(b"ok\x00");
+            ok.extend(1.0f64.to_le_bytes());
+            let mut doc = ((ok.len() + 5) as i32).to_le_bytes().to_vec();
+            doc.extend(&ok);
+            doc.push(0);
+            s.write_all(&op_msg_reply(doc)).unwrap();
+
+            read_some(&mut s);
+            let fields = bson_document(&[("version", Bson::String("7.0.5".into()))]);
+            // Append ok: 1.0 before the terminator.
+            let mut doc = fields[4..fields.len() - 1].to_vec();
+            doc.extend(&ok);
+            let mut full = ((doc.len() + 5) as i32).to_le_bytes().to_vec();
+            full.extend(doc);
+            full.push(0);
+            s.write_all(&op_msg_reply(full)).unwrap();
+        });
+        assert_eq!(
+            probe("mongo", &target(port)),
+            Health::up(Some("7.0.5".into()))
+        );
+    }
+
+    #[test]
+    fn test_amqp_connection_start() {
+        let port = serve(|mut s| {
+            let mut hdr = [0u8; 8];
+            s.read_exact(&mut hdr).unwrap();
+            assert_eq!(&hdr, b"AMQP\x00\x00\x09\x01");
+
+            let mut table = Vec::new();
+            table.push(7);
+            table.extend(b"product");
+            table.push(b'S');
+            table.extend(8u32.to_be_bytes());
+            table.extend(b"RabbitMQ");
+            table.push(7);
+            table.extend(b"version");
+            table.push(b'S');
+            table.extend(6u32.to_be_bytes());
+            table.extend(b"3.12.1");
+
+            let mut payload = vec![0, 10, 0, 10, 0, 9];
+            payload.extend((table.len() as u32).to_be_bytes());
+            payload.extend(table);
+            let mut frame = vec![1, 0, 0];
+            frame.extend((payload.len() as u32).to_be_bytes());
+            frame.extend(payload);
+            frame.push(0xce);
+            s.write_all(&frame).unwrap();
+        });
+        assert_eq!(
+            probe("rabbitmq", &target(port)),
+            Health::up(Some("3.12.1".into()))
+        );
+    }
+
+    #[test]
+    fn test_memcached_version() {
+        let port = serve(|mut s| {
+            read_some(&mut s);
+            s.write_all(b"VERSION 1.6.21\r\n").unwrap();
+        });
+        assert_eq!(
+            probe("memcached", &target(port)),
+            Health::up(Some("1.6.21".into()))
+        );
+    }
+
+    #[test]
+    fn test_elasticsearch_http() {
+        let port = serve(|mut s| {
+            let req = read_some(&mut s);
+            assert!(req.starts_with(b"GET / HTTP/1.0"));
+            s.write_all(
+                b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\r\n{\"version\":{\"number\":\"8.12.0\"}}",
+            )
+            .unwrap();
+        });
+        assert_eq!(
+            probe("elasticsearch", &target(port)),
+            Health::up(Some("8.12.0".into()))
+        );
+    }
+
+    #[test]
+    fn test_http_server_error() {
+        let port = serve(|mut s| {
+            read_some(&mut s);
+            s.write_all(b"HTTP/1.1 503 Service Unavailable\r\n\r\n")
+                .unwrap();
+        });
+        assert_eq!(
+            probe("minio", &target(port)),
+            Health::Unhealthy("HTTP 503".into())
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+        assert_eq!(probe("redis", &target(port)), Health::Unreachable);
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b"user:pass"), "dXNlcjpwYXNz");
+        assert_eq!(base64(b"a"), "YQ==");
+        assert_eq!(base64(b"ab"), "YWI=");
+    }
+}
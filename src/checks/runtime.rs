@@ -1,122 +1,707 @@
-use crate::check::{CheckResult, Status};
+use std::path::Path;
+
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
 use crate::config::VitalsConfig;
-use crate::detect::ProjectContext;
+use crate::detect::{self, DotnetReq, GoContext, ProjectContext, RuntimeReq};
+use crate::parallel;
 use crate::platform::Platform;
 use crate::version;
+use crate::workspace;
+
+pub struct RuntimeCheck;
+
+impl Check for RuntimeCheck {
+    fn id(&self) -> &'static str {
+        "runtime"
+    }
+
+    fn category(&self) -> &'static str {
+        "Runtime"
+    }
+
+    fn description(&self) -> &'static str {
+        "Installed runtimes match version requirements"
+    }
+
+    fn applies(&self, ctx: &ProjectContext, _config: &VitalsConfig) -> bool {
+        !ctx.runtimes.is_empty()
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        platform: &Platform,
+    ) -> Vec<CheckResult> {
+        check(ctx, config, platform)
+    }
+}
 
 /// Runtime command definitions: (name, [(binary, args)]).
 struct RuntimeDef {
     name: &'static str,
     commands: &'static [(&'static str, &'static [&'static str])],
+    /// Regex whose first group is the version, for output that mentions
+    /// several versions. Defaults to the first `X.Y[.Z]`.
+    pattern: Option<&'static str>,
 }
 
 const RUNTIMES: &[RuntimeDef] = &[
     RuntimeDef {
         name: "node",
         commands: &[("node", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "bun",
+        commands: &[("bun", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "deno",
+        commands: &[("deno", &["--version"])],
+        // "deno 1.44.0 (release, ...)" followed by the v8 and TypeScript versions.
+        pattern: Some(r"deno (\d+\.\d+\.\d+)"),
     },
     RuntimeDef {
         name: "python",
         commands: &[("python3", &["--version"]), ("python", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "uv",
+        commands: &[("uv", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "poetry",
+        // "Poetry (version 1.8.3)"
+        commands: &[("poetry", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "pdm",
+        commands: &[("pdm", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "hatch",
+        commands: &[("hatch", &["--version"])],
+        pattern: None,
     },
     RuntimeDef {
         name: "rust",
         commands: &[("rustc", &["--version"])],
+        pattern: None,
     },
     RuntimeDef {
         name: "go",
         commands: &[("go", &["version"])],
+        pattern: None,
     },
     RuntimeDef {
         name: "ruby",
         commands: &[("ruby", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "bundler",
+        commands: &[("bundle", &["--version"])],
+        pattern: None,
     },
     RuntimeDef {
         name: "java",
-        commands: &[("java", &["--version"])],
+        // Java 8 only knows `-version` and prints "openjdk version \"1.8.0_392\"".
+        commands: &[("java", &["--version"]), ("java", &["-version"])],
+        pattern: Some(r#"(?:openjdk|java|version)\s+"?(\d+(?:\.\d+)*(?:_\d+)?)"#),
+    },
+    RuntimeDef {
+        name: "maven",
+        commands: &[("mvn", &["--version"])],
+        // "Apache Maven 3.9.6 (bc0240f...)" then "Java version: 17.0.9".
+        pattern: Some(r"Apache Maven (\d+\.\d+(?:\.\d+)?)"),
+    },
+    RuntimeDef {
+        name: "gradle",
+        commands: &[("gradle", &["--version"])],
+        pattern: Some(r"Gradle (\d+\.\d+(?:\.\d+)?)"),
+    },
+    RuntimeDef {
+        name: "php",
+        commands: &[("php", &["--version"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "kubectl",
+        commands: &[("kubectl", &["version", "--client"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "helm",
+        commands: &[("helm", &["version", "--short"])],
+        pattern: None,
+    },
+    RuntimeDef {
+        name: "elixir",
+        commands: &[("elixir", &["--version"])],
+        // "Erlang/OTP 26 [erts-14.2.1] ... Elixir 1.15.7 (compiled with Erlang/OTP 26)"
+        pattern: Some(r"Elixir (\d+\.\d+(?:\.\d+)?)"),
+    },
+    RuntimeDef {
+        name: "erlang",
+        commands: &[
+            // Full OTP version ("26.2.1"); otp_release alone is just "26".
+            (
+                "erl",
+                &[
+                    "-noshell",
+                    "-eval",
+                    "{ok, V} = file:read_file(filename:join([code:root_dir(), \"releases\", \
+                     erlang:system_info(otp_release), \"OTP_VERSION\"])), io:fwrite(V), halt().",
+                ],
+            ),
+            ("elixir", &["--version"]),
+        ],
+        pattern: Some(r"(?:^|Erlang/OTP )(\d+(?:\.\d+)*)"),
     },
 ];
 
+/// asdf/mise plugins pinned in `.tool-versions` or `mise.toml` that have no
+/// [`RuntimeDef`], with the binary that answers `--version`. Other plugins
+/// are skipped when they can't be probed.
+const TOOLS: &[(&str, &str)] = &[
+    ("1password-cli", "op"),
+    ("awscli", "aws"),
+    ("azure-cli", "az"),
+    ("gcloud", "gcloud"),
+    ("github-cli", "gh"),
+    ("golangci-lint", "golangci-lint"),
+    ("just", "just"),
+    ("neovim", "nvim"),
+    ("npm", "npm"),
+    ("opentofu", "tofu"),
+    ("packer", "packer"),
+    ("pnpm", "pnpm"),
+    ("protoc", "protoc"),
+    ("ripgrep", "rg"),
+    ("terraform", "terraform"),
+    ("vault", "vault"),
+    ("yarn", "yarn"),
+];
+
 pub fn check(ctx: &ProjectContext, config: &VitalsConfig, platform: &Platform) -> Vec<CheckResult> {
-    let mut results = Vec::new();
+    // Each probe spawns processes, so run them side by side.
+    let workers = config
+        .checks
+        .workers
+        .unwrap_or_else(parallel::default_workers);
+    parallel::map(ctx.runtimes.iter().collect(), workers, |req| {
+        let result = match req.name.as_str() {
+            "dotnet" => check_dotnet(req, ctx.dotnet.as_ref(), config, platform),
+            "go" => check_go(req, ctx.go.as_ref(), &ctx.dir, config, platform),
+            _ => check_one(req, &ctx.dir, config, platform),
+        };
+        let installed = result.found != "not found";
+        let mut results = vec![with_conflicts(result, req)];
+        if installed && !req.extensions.is_empty() {
+            results.push(check_extensions(req, platform));
+        }
+        if installed && req.name == "go" {
+            results.extend(check_go_env(config));
+        }
+        if installed && req.name == "java" {
+            let on_path = results[0].found.trim_start_matches('v').to_string();
+            results.extend(check_java_home(&on_path, platform));
+        }
+        results
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
 
-    for req in &ctx.runtimes {
-        let def = RUNTIMES.iter().find(|d| d.name == req.name);
+fn check_one(
+    req: &RuntimeReq,
+    dir: &Path,
+    config: &VitalsConfig,
+    platform: &Platform,
+) -> CheckResult {
+    let def = RUNTIMES.iter().find(|d| d.name == req.name);
 
-        // Config overrides auto-detected version requirement.
-        let version_req = get_config_req(config, &req.name).or_else(|| req.version_req.clone());
+    // Config overrides auto-detected version requirement.
+    let version_req = get_config_req(config, &req.name).or_else(|| req.version_req.clone());
 
-        // Try to get installed version.
-        let found_version = def.and_then(|d| {
-            for (cmd, args) in d.commands {
-                if let Some(output) = version::run_cmd(cmd, args) {
-                    if let Some(ver) = version::extract_version(&output) {
-                        return Some(ver);
-                    }
-                }
-            }
-            None
-        });
-
-        match found_version {
-            Some(ver) => {
-                if let Some(ref vr) = version_req {
-                    if version::meets_requirement(&ver, vr) {
-                        results.push(CheckResult {
-                            category: "Runtime".into(),
-                            name: req.name.clone(),
-                            status: Status::Pass,
-                            found: format!("v{}", ver),
-                            expected: format!("{} ({})", vr, req.source),
-                            fix: None,
-                            details: vec![],
-                        });
-                    } else {
-                        results.push(CheckResult {
-                            category: "Runtime".into(),
-                            name: req.name.clone(),
-                            status: Status::Fail,
-                            found: format!("v{}", ver),
-                            expected: format!("{} ({})", vr, req.source),
-                            fix: Some(upgrade_hint(&req.name, vr)),
-                            details: vec![],
-                        });
-                    }
-                } else {
-                    // No version requirement — just check if installed.
-                    results.push(CheckResult {
+    // Try to get installed version. Tools without a definition (pinned in
+    // .tool-versions, e.g. "terraform") get the usual version flags.
+    let tool = TOOLS
+        .iter()
+        .find(|(t, _)| *t == req.name)
+        .map(|(_, bin)| *bin);
+    let binary = tool.unwrap_or(&req.name);
+    let generic: [(&str, &[&str]); 2] = [(binary, &["--version"]), (binary, &["version"])];
+    let (commands, pattern) = match def {
+        Some(d) => (d.commands, d.pattern),
+        None => (&generic[..], None),
+    };
+    let found_version = commands.iter().find_map(|(cmd, args)| {
+        let output = version::run_cmd_in(dir, cmd, args)?;
+        match pattern {
+            Some(pattern) => version::extract_version_with(&output, pattern),
+            None => version::extract_version(&output),
+        }
+    });
+
+    match found_version {
+        Some(ver) => {
+            if let Some(ref vr) = version_req {
+                if version::meets_requirement(&ver, vr) {
+                    CheckResult {
                         category: "Runtime".into(),
+                        package: None,
                         name: req.name.clone(),
                         status: Status::Pass,
                         found: format!("v{}", ver),
-                        expected: "installed".into(),
+                        expected: format!("{} ({})", vr, req.source),
                         fix: None,
                         details: vec![],
-                    });
+                    }
+                } else {
+                    CheckResult {
+                        category: "Runtime".into(),
+                        package: None,
+                        name: req.name.clone(),
+                        status: Status::Fail,
+                        found: format!("v{}", ver),
+                        expected: format!("{} ({})", vr, req.source),
+                        fix: Some(
+                            manager_fix(&req.source)
+                                .or_else(|| package_manager_fix(req, Some(vr), true))
+                                .unwrap_or_else(|| upgrade_hint(&req.name, vr)),
+                        ),
+                        details: vec![],
+                    }
+                }
+            } else {
+                // No version requirement — just check if installed.
+                CheckResult {
+                    category: "Runtime".into(),
+                    package: None,
+                    name: req.name.clone(),
+                    status: Status::Pass,
+                    found: format!("v{}", ver),
+                    expected: "installed".into(),
+                    fix: None,
+                    details: vec![],
                 }
             }
-            None => {
-                let expected = if let Some(ref vr) = version_req {
-                    format!("{} ({})", vr, req.source)
-                } else {
-                    "installed".into()
-                };
-                results.push(CheckResult {
+        }
+        None => {
+            let expected = if let Some(ref vr) = version_req {
+                format!("{} ({})", vr, req.source)
+            } else {
+                "installed".into()
+            };
+            // An asdf/mise plugin vitals doesn't know may name its binary
+            // differently or not take `--version`.
+            if def.is_none() && tool.is_none() {
+                return CheckResult {
                     category: "Runtime".into(),
+                    package: None,
                     name: req.name.clone(),
-                    status: Status::Fail,
+                    status: Status::Skip,
                     found: "not found".into(),
                     expected,
-                    fix: Some(platform.install_hint(&req.name)),
-                    details: vec![],
-                });
+                    fix: None,
+                    details: vec![format!(
+                        "no `{}` binary reporting a version; unknown {} plugin",
+                        req.name, req.source
+                    )],
+                };
+            }
+            CheckResult {
+                category: "Runtime".into(),
+                package: None,
+                name: req.name.clone(),
+                status: Status::Fail,
+                found: "not found".into(),
+                expected,
+                fix: Some(
+                    manager_fix(&req.source)
+                        .or_else(|| package_manager_fix(req, version_req.as_deref(), false))
+                        .unwrap_or_else(|| FixAction::confirm(platform.install_hint(&req.name))),
+                ),
+                details: vec![],
+            }
+        }
+    }
+}
+
+// ── Go ──────────────────────────────────────────────────────────────────────
+
+/// Go 1.21+ picks its toolchain per module: with `GOTOOLCHAIN=auto` (the
+/// default) an older local `go` switches to the version go.mod's `go` or
+/// `toolchain` line asks for, downloading it on first use.
+fn check_go(
+    req: &RuntimeReq,
+    go: Option<&GoContext>,
+    dir: &Path,
+    config: &VitalsConfig,
+    platform: &Platform,
+) -> CheckResult {
+    // GOTOOLCHAIN=local keeps the probe itself from switching (and downloading).
+    let local = version::run_cmd_env(dir, "go", &["version"], &[("GOTOOLCHAIN", "local")])
+        .and_then(|out| version::extract_version(&out));
+    let Some(local) = local else {
+        return check_one(req, dir, config, platform);
+    };
+
+    let setting = detect::go_env("GOTOOLCHAIN")
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "auto".into());
+    let auto = go_auto_switch(&setting);
+    let (base, switch) = go_toolchain_selection(&setting, &local, go);
+
+    let version_req = get_config_req(config, "go").or_else(|| req.version_req.clone());
+    let expected = match &version_req {
+        Some(vr) => format!("{} ({})", vr, req.source),
+        None => "installed".into(),
+    };
+    let meets = |v: &str| {
+        version_req
+            .as_deref()
+            .is_none_or(|vr| version::meets_requirement(v, vr))
+    };
+    let result =
+        |status: Status, found: String, fix: Option<FixAction>, details: Vec<String>| CheckResult {
+            category: "Runtime".into(),
+            package: None,
+            name: req.name.clone(),
+            status,
+            found,
+            expected: expected.clone(),
+            fix,
+            details,
+        };
+
+    match switch {
+        Some(wanted) if meets(&wanted) => {
+            let found = format!("v{} → go{}", base, wanted);
+            if go_toolchain_cached(&detect::go_mod_cache(), &wanted) {
+                result(
+                    Status::Pass,
+                    found,
+                    None,
+                    vec![format!(
+                        "GOTOOLCHAIN={} switches to cached go{}",
+                        setting, wanted
+                    )],
+                )
+            } else {
+                result(
+                    Status::Warn,
+                    found,
+                    Some(FixAction::safe("go mod download", dir)),
+                    vec![format!(
+                        "go{} is downloaded on first use (needs network access)",
+                        wanted
+                    )],
+                )
             }
         }
+        _ if meets(&base) => result(Status::Pass, format!("v{}", base), None, vec![]),
+        _ => {
+            let required = version_req.as_deref().unwrap_or_default();
+            let fix = manager_fix(&req.source).unwrap_or_else(|| {
+                if auto {
+                    upgrade_hint("go", required)
+                } else {
+                    FixAction::manual(format!(
+                        "update Go to {} (or unset GOTOOLCHAIN={} to let go fetch it)",
+                        required, setting
+                    ))
+                }
+            });
+            result(Status::Fail, format!("v{}", base), Some(fix), vec![])
+        }
+    }
+}
+
+/// Whether GOTOOLCHAIN lets `go` switch to a newer release (`auto`,
+/// `go1.22.3+auto`).
+fn go_auto_switch(setting: &str) -> bool {
+    setting == "auto" || setting.ends_with("+auto")
+}
+
+/// The release `go` starts from under GOTOOLCHAIN=`setting` with `local`
+/// installed, and the one it switches to for this module, if any: the
+/// newest of go.mod's `go` and `toolchain` lines, when switching is allowed
+/// and that is newer.
+fn go_toolchain_selection(
+    setting: &str,
+    local: &str,
+    go: Option<&GoContext>,
+) -> (String, Option<String>) {
+    // GOTOOLCHAIN=go1.22.3 forces that release instead of the local one.
+    let base = setting
+        .strip_prefix("go")
+        .map(|v| v.split('+').next().unwrap_or(v).to_string())
+        .unwrap_or_else(|| local.to_string());
+    let newer = |a: &str, b: &str| version::meets_requirement(a, &format!(">{}", b));
+    let wanted = go.and_then(|g| match (&g.go_version, &g.toolchain) {
+        (Some(v), Some(t)) if newer(v, t) => Some(v.clone()),
+        (_, Some(t)) => Some(t.clone()),
+        (Some(v), None) => Some(v.clone()),
+        (None, None) => None,
+    });
+    let switch = wanted.filter(|w| go_auto_switch(setting) && newer(w, &base));
+    (base, switch)
+}
+
+/// Whether `golang.org/toolchain@v0.0.1-go<version>.<os>-<arch>` is in the
+/// module cache.
+fn go_toolchain_cached(cache: &Path, version: &str) -> bool {
+    let prefix = format!("v0.0.1-go{}.", version);
+    let has_entry = |dir: std::path::PathBuf, prefix: &str| {
+        std::fs::read_dir(dir).is_ok_and(|entries| {
+            entries
+                .flatten()
+                .any(|e| e.file_name().to_string_lossy().starts_with(prefix))
+        })
+    };
+    has_entry(cache.join("golang.org"), &format!("toolchain@{}", prefix))
+        || has_entry(
+            cache.join("cache/download/golang.org/toolchain/@v"),
+            &prefix,
+        )
+}
+
+/// `[go] private` and `flags` from .vitals.toml against `go env`.
+fn check_go_env(config: &VitalsConfig) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+
+    if !config.go.private.is_empty() {
+        let current = detect::go_env("GOPRIVATE").unwrap_or_default();
+        let patterns: Vec<&str> = current
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .collect();
+        let missing: Vec<&str> = config
+            .go
+            .private
+            .iter()
+            .map(String::as_str)
+            .filter(|w| !goprivate_covers(&patterns, w))
+            .collect();
+        let merged: Vec<&str> = patterns
+            .iter()
+            .copied()
+            .chain(missing.iter().copied())
+            .collect();
+        results.push(go_env_result(
+            "GOPRIVATE",
+            &current,
+            config.go.private.join(","),
+            &missing,
+            format!("go env -w GOPRIVATE={}", merged.join(",")),
+        ));
     }
 
+    if !config.go.flags.is_empty() {
+        let current = detect::go_env("GOFLAGS").unwrap_or_default();
+        let missing: Vec<&str> = config
+            .go
+            .flags
+            .iter()
+            .map(String::as_str)
+            .filter(|f| !current.split_whitespace().any(|c| c == *f))
+            .collect();
+        let merged: Vec<&str> = current
+            .split_whitespace()
+            .chain(missing.iter().copied())
+            .collect();
+        results.push(go_env_result(
+            "GOFLAGS",
+            &current,
+            config.go.flags.join(" "),
+            &missing,
+            format!("go env -w GOFLAGS=\"{}\"", merged.join(" ")),
+        ));
+    }
     results
 }
 
+/// Whether GOPRIVATE `patterns` cover `module`: each pattern matches a
+/// module path prefix, element by element.
+fn goprivate_covers(patterns: &[&str], module: &str) -> bool {
+    let elements: Vec<&str> = module.split('/').collect();
+    patterns.iter().any(|p| {
+        let n = p.split('/').count();
+        n <= elements.len() && workspace::glob_match(p, &elements[..n].join("/"))
+    })
+}
+
+fn go_env_result(
+    name: &str,
+    current: &str,
+    wanted: String,
+    missing: &[&str],
+    fix: String,
+) -> CheckResult {
+    let found = if current.is_empty() {
+        "not set".into()
+    } else {
+        current.to_string()
+    };
+    CheckResult {
+        category: "Runtime".into(),
+        package: None,
+        name: name.into(),
+        status: if missing.is_empty() {
+            Status::Pass
+        } else {
+            Status::Fail
+        },
+        found,
+        expected: format!("{} (.vitals.toml)", wanted),
+        fix: (!missing.is_empty()).then(|| FixAction::confirm(fix)),
+        details: missing.iter().map(|m| format!("missing: {}", m)).collect(),
+    }
+}
+
+/// Maven and Gradle run on `$JAVA_HOME` when it is set, so it has to be the
+/// same JDK as `java` on PATH.
+fn check_java_home(on_path: &str, platform: &Platform) -> Option<CheckResult> {
+    let home = std::env::var_os("JAVA_HOME").filter(|h| !h.is_empty())?;
+    let java = Path::new(&home).join("bin").join("java");
+    let pattern = RUNTIMES.iter().find(|d| d.name == "java")?.pattern?;
+    let home_version = version::run_cmd(&java.to_string_lossy(), &["-version"])
+        .and_then(|out| version::extract_version_with(&out, pattern));
+
+    let fix = match platform {
+        Platform::MacOS => format!(
+            "export JAVA_HOME=$(/usr/libexec/java_home -v {})",
+            version::normalize_version(on_path)
+                .split('.')
+                .next()
+                .unwrap_or_default()
+        ),
+        _ => "export JAVA_HOME=$(dirname $(dirname $(readlink -f $(command -v java))))".into(),
+    };
+    let (status, found, fix) = match home_version {
+        None => (
+            Status::Fail,
+            format!("{} has no bin/java", home.to_string_lossy()),
+            Some(FixAction::manual(fix)),
+        ),
+        Some(v) if version::normalize_version(&v) != version::normalize_version(on_path) => (
+            Status::Warn,
+            format!("v{}", v),
+            Some(FixAction::manual(fix)),
+        ),
+        Some(v) => (Status::Pass, format!("v{}", v), None),
+    };
+    Some(CheckResult {
+        category: "Runtime".into(),
+        package: None,
+        name: "JAVA_HOME".into(),
+        status,
+        found,
+        expected: format!("v{} (java on PATH)", on_path),
+        fix,
+        details: vec![],
+    })
+}
+
+/// Versions pinned by asdf/mise or SDKMAN! are installed with the tool manager.
+fn manager_fix(source: &str) -> Option<FixAction> {
+    if source == ".sdkmanrc" {
+        return Some(FixAction::confirm("sdk env install"));
+    }
+    if source.contains("mise") {
+        return Some(FixAction::confirm("mise install"));
+    }
+    if source == ".tool-versions" {
+        // mise reads .tool-versions too; prefer it when it's what is installed.
+        let manager = if version::run_cmd("mise", &["--version"]).is_some() {
+            "mise"
+        } else {
+            "asdf"
+        };
+        return Some(FixAction::confirm(format!("{} install", manager)));
+    }
+    None
+}
+
+/// Node package managers: Corepack provides the version pinned by
+/// `packageManager`; otherwise install the required version with npm.
+fn package_manager_fix(
+    req: &RuntimeReq,
+    required: Option<&str>,
+    installed: bool,
+) -> Option<FixAction> {
+    let spec = match required {
+        Some(r) => format!("\"{}@{}\"", req.name, r.trim_start_matches('=')),
+        None => req.name.clone(),
+    };
+    match req.name.as_str() {
+        "yarn" | "pnpm" => {
+            let corepack = version::run_cmd("corepack", &["--version"]).is_some();
+            if corepack && req.source.contains("packageManager") {
+                Some(FixAction::confirm("corepack enable"))
+            } else {
+                Some(FixAction::confirm(format!("npm install -g {}", spec)))
+            }
+        }
+        "npm" => Some(FixAction::confirm(format!("npm install -g {}", spec))),
+        // Python managers update themselves; pipx installs the rest.
+        "uv" | "poetry" if installed => {
+            let target = required.map(version::min_version).unwrap_or_default();
+            Some(FixAction::confirm(
+                format!("{} self update {}", req.name, target).trim_end(),
+            ))
+        }
+        "pdm" if installed => Some(FixAction::confirm("pdm self update")),
+        "uv" => Some(FixAction::manual(
+            "curl -LsSf https://astral.sh/uv/install.sh | sh",
+        )),
+        "hatch" if installed => Some(FixAction::confirm("pipx upgrade hatch")),
+        "poetry" | "pdm" | "hatch" => Some(FixAction::confirm(match required {
+            Some(r) => format!("pipx install \"{}{}\"", req.name, r.replace(' ', "")),
+            None => format!("pipx install {}", req.name),
+        })),
+        "bun" if installed => Some(FixAction::confirm("bun upgrade")),
+        "bun" => Some(FixAction::manual(
+            "curl -fsSL https://bun.sh/install | bash",
+        )),
+        _ => None,
+    }
+}
+
+/// Surface disagreeing version sources: a passing runtime becomes a warning.
+fn with_conflicts(mut result: CheckResult, req: &RuntimeReq) -> CheckResult {
+    if req.conflicts.is_empty() {
+        return result;
+    }
+    result
+        .details
+        .extend(req.conflicts.iter().map(|c| format!("conflict: {}", c)));
+    if result.status == Status::Pass {
+        result.status = Status::Warn;
+        result.fix = Some(FixAction::manual(format!(
+            "make {} agree with {}",
+            req.conflicts
+                .iter()
+                .filter_map(|c| c.split(" wants ").next())
+                .collect::<Vec<_>>()
+                .join(", "),
+            req.source
+        )));
+    }
+    result
+}
+
 fn get_config_req(config: &VitalsConfig, name: &str) -> Option<String> {
     match name {
         "node" => config.require.node.clone(),
@@ -124,17 +709,479 @@ fn get_config_req(config: &VitalsConfig, name: &str) -> Option<String> {
         "rust" => config.require.rust.clone(),
         "go" => config.require.go.clone(),
         "ruby" => config.require.ruby.clone(),
-        "java" => config.require.java.clone(),
+        "java" => config
+            .require
+            .java
+            .as_deref()
+            .map(version::java_requirement),
+        "php" => config.require.php.clone(),
+        "dotnet" => config.require.dotnet.clone(),
+        "elixir" => config.require.elixir.clone(),
+        "erlang" => config.require.erlang.clone(),
+        "bun" => config.require.bun.clone(),
+        "deno" => config.require.deno.clone(),
+        "maven" => config.require.maven.clone(),
+        "gradle" => config.require.gradle.clone(),
         _ => None,
     }
 }
 
-fn upgrade_hint(name: &str, required: &str) -> String {
+fn upgrade_hint(name: &str, required: &str) -> FixAction {
     match name {
-        "node" => format!("nvm install {} (or update Node.js)", required),
-        "python" => format!("pyenv install {} (or update Python)", required),
-        "rust" => "rustup update stable".into(),
-        "go" => format!("update Go to {}", required),
-        _ => format!("update {} to {}", name, required),
+        "node" => FixAction::manual(format!("nvm install {} (or update Node.js)", required)),
+        "python" => FixAction::manual(format!("pyenv install {} (or update Python)", required)),
+        "rust" => FixAction::confirm("rustup update stable"),
+        "go" => FixAction::manual(format!("update Go to {}", required)),
+        "deno" => FixAction::confirm("deno upgrade"),
+        // Gemfile.lock's BUNDLED WITH ("^2.4.10").
+        "bundler" => FixAction::confirm(format!(
+            "gem install bundler -v {}",
+            version::min_version(required)
+        )),
+        _ => FixAction::manual(format!("update {} to {}", name, required)),
+    }
+}
+
+/// Check that the runtime has the required extensions loaded (PHP `ext-*`).
+fn check_extensions(req: &RuntimeReq, platform: &Platform) -> CheckResult {
+    let loaded: Vec<String> = version::run_cmd("php", &["-m"])
+        .map(|out| {
+            out.lines()
+                .map(|l| l.trim().to_lowercase().replace(' ', "-"))
+                .filter(|l| !l.is_empty() && !l.starts_with('['))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let missing: Vec<&str> = req
+        .extensions
+        .iter()
+        .filter(|ext| !loaded.contains(&ext.to_lowercase()))
+        .map(String::as_str)
+        .collect();
+
+    let name = format!("{} extensions", req.name);
+    let expected = format!("{} (composer.json)", req.extensions.join(", "));
+    if missing.is_empty() {
+        return CheckResult {
+            category: "Runtime".into(),
+            package: None,
+            name,
+            status: Status::Pass,
+            found: format!("{} loaded", req.extensions.len()),
+            expected,
+            fix: None,
+            details: vec![],
+        };
+    }
+
+    let packages: Vec<String> = missing.iter().map(|ext| format!("php-{}", ext)).collect();
+    let fix = match platform {
+        Platform::Debian | Platform::Fedora => {
+            FixAction::confirm(platform.install_hint(&packages.join(" ")))
+        }
+        _ => FixAction::manual(format!(
+            "pecl install {} (or enable it in php.ini)",
+            missing.join(" ")
+        )),
+    };
+    CheckResult {
+        category: "Runtime".into(),
+        package: None,
+        name,
+        status: Status::Fail,
+        found: format!("missing {}", missing.join(", ")),
+        expected,
+        fix: Some(fix),
+        details: vec![],
+    }
+}
+
+// ── .NET SDK ────────────────────────────────────────────────────────────────
+
+/// An SDK version like "8.0.204": feature band 2, patch 4.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct SdkVersion {
+    major: u64,
+    minor: u64,
+    band: u64,
+    patch: u64,
+    /// Sorts after a prerelease with the same numbers.
+    release: bool,
+    raw: String,
+}
+
+impl SdkVersion {
+    fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim();
+        let (numbers, pre) = match raw.split_once('-') {
+            Some((n, _)) => (n, true),
+            None => (raw, false),
+        };
+        let mut parts = numbers.split('.').map(|p| p.parse::<u64>());
+        let (major, minor, feature) = (
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+        );
+        Some(SdkVersion {
+            major,
+            minor,
+            band: feature / 100,
+            patch: feature % 100,
+            release: !pre,
+            raw: raw.to_string(),
+        })
+    }
+
+    fn numbers(&self) -> (u64, u64, u64, u64) {
+        (self.major, self.minor, self.band, self.patch)
+    }
+
+    /// Plain "X.Y.Z" for `version::meets_requirement`.
+    fn semver(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            self.major,
+            self.minor,
+            self.band * 100 + self.patch
+        )
+    }
+}
+
+/// Pick the SDK `dotnet` would use for a global.json `version` and
+/// `rollForward` policy, following the SDK's roll-forward rules.
+fn select_sdk<'a>(
+    installed: &'a [SdkVersion],
+    requested: &SdkVersion,
+    policy: &str,
+    allow_prerelease: bool,
+) -> Option<&'a SdkVersion> {
+    let candidates: Vec<&SdkVersion> = installed
+        .iter()
+        .filter(|s| (allow_prerelease || s.release) && s.numbers() >= requested.numbers())
+        .collect();
+    let latest =
+        |keep: &dyn Fn(&SdkVersion) -> bool| candidates.iter().copied().filter(|s| keep(s)).max();
+    let same_minor = |s: &SdkVersion| (s.major, s.minor) == (requested.major, requested.minor);
+    let same_band = |s: &SdkVersion| same_minor(s) && s.band == requested.band;
+
+    match policy {
+        "disable" => candidates.iter().copied().find(|s| s.raw == requested.raw),
+        "patch" => candidates
+            .iter()
+            .copied()
+            .find(|s| s.raw == requested.raw)
+            .or_else(|| latest(&same_band)),
+        "feature" | "minor" | "major" => latest(&same_band).or_else(|| {
+            // Roll to the lowest higher band within scope, then its latest patch.
+            let in_scope = |s: &SdkVersion| match policy {
+                "feature" => same_minor(s),
+                "minor" => s.major == requested.major,
+                _ => true,
+            };
+            let next = candidates
+                .iter()
+                .filter(|s| in_scope(s))
+                .map(|s| (s.major, s.minor, s.band))
+                .min()?;
+            latest(&|s| (s.major, s.minor, s.band) == next)
+        }),
+        "latestFeature" => latest(&same_minor),
+        "latestMinor" => latest(&|s| s.major == requested.major),
+        "latestMajor" => latest(&|_| true),
+        // "latestPatch" is the default when a version is given.
+        _ => latest(&same_band),
+    }
+}
+
+fn check_dotnet(
+    req: &RuntimeReq,
+    dotnet: Option<&DotnetReq>,
+    config: &VitalsConfig,
+    platform: &Platform,
+) -> CheckResult {
+    // `dotnet --version` fails outright when global.json can't be satisfied,
+    // so list every installed SDK instead.
+    let mut installed: Vec<SdkVersion> = version::run_cmd("dotnet", &["--list-sdks"])
+        .map(|out| {
+            out.lines()
+                .filter_map(|l| SdkVersion::parse(l.split_whitespace().next()?))
+                .collect()
+        })
+        .unwrap_or_default();
+    installed.sort();
+
+    let config_req = get_config_req(config, "dotnet");
+    let global = dotnet
+        .and_then(|d| d.sdk_version.as_deref())
+        .and_then(SdkVersion::parse)
+        .filter(|_| config_req.is_none());
+
+    let version_req = config_req.or_else(|| req.version_req.clone());
+    let mut expected = match &version_req {
+        Some(vr) => format!("{} ({})", vr, req.source),
+        None => "installed".into(),
+    };
+
+    if installed.is_empty() {
+        let fix = match platform {
+            Platform::Debian | Platform::Fedora => {
+                let (major, minor) = global
+                    .as_ref()
+                    .map(|g| (g.major, g.minor))
+                    .or_else(|| version_req.as_deref().and_then(requirement_major_minor))
+                    .unwrap_or((8, 0));
+                platform.install_hint(&format!("dotnet-sdk-{}.{}", major, minor))
+            }
+            _ => platform.install_hint("dotnet"),
+        };
+        return CheckResult {
+            category: "Runtime".into(),
+            package: None,
+            name: req.name.clone(),
+            status: Status::Fail,
+            found: "not found".into(),
+            expected,
+            fix: Some(FixAction::confirm(fix)),
+            details: vec![],
+        };
+    }
+
+    let listing = || {
+        let versions: Vec<&str> = installed.iter().map(|s| s.raw.as_str()).collect();
+        format!("installed: {}", versions.join(", "))
+    };
+
+    if let Some(requested) = global {
+        let policy = dotnet
+            .and_then(|d| d.roll_forward.as_deref())
+            .unwrap_or("latestPatch");
+        let allow_prerelease = dotnet.and_then(|d| d.allow_prerelease).unwrap_or(true);
+        expected = format!("{} (global.json, rollForward: {})", requested.raw, policy);
+
+        return match select_sdk(&installed, &requested, policy, allow_prerelease) {
+            Some(sdk) => {
+                let mut details = vec![];
+                if sdk.raw != requested.raw {
+                    details.push(format!("rolls forward from {}", requested.raw));
+                }
+                CheckResult {
+                    category: "Runtime".into(),
+                    package: None,
+                    name: req.name.clone(),
+                    status: Status::Pass,
+                    found: format!("v{}", sdk.raw),
+                    expected,
+                    fix: None,
+                    details,
+                }
+            }
+            None => CheckResult {
+                category: "Runtime".into(),
+                package: None,
+                name: req.name.clone(),
+                status: Status::Fail,
+                found: "no matching SDK".into(),
+                expected,
+                fix: Some(dotnet_hint(requested.major, requested.minor)),
+                details: vec![listing()],
+            },
+        };
+    }
+
+    // No global.json: the newest installed SDK is used.
+    let latest = installed.last().expect("checked non-empty");
+    let ok = version_req
+        .as_deref()
+        .is_none_or(|vr| version::meets_requirement(&latest.semver(), vr));
+    CheckResult {
+        category: "Runtime".into(),
+        package: None,
+        name: req.name.clone(),
+        status: if ok { Status::Pass } else { Status::Fail },
+        found: format!("v{}", latest.raw),
+        expected,
+        fix: (!ok).then(|| {
+            let (major, minor) = version_req
+                .as_deref()
+                .and_then(requirement_major_minor)
+                .unwrap_or((latest.major, latest.minor));
+            dotnet_hint(major, minor)
+        }),
+        details: if ok { vec![] } else { vec![listing()] },
+    }
+}
+
+fn dotnet_hint(major: u64, minor: u64) -> FixAction {
+    FixAction::manual(format!(
+        "install the .NET {0}.{1} SDK: https://dotnet.microsoft.com/download/dotnet/{0}.{1}",
+        major, minor
+    ))
+}
+
+/// ">=8.0" → (8, 0).
+fn requirement_major_minor(req: &str) -> Option<(u64, u64)> {
+    let v = req.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let mut parts = v.split(|c: char| !c.is_ascii_digit());
+    Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sdks(versions: &[&str]) -> Vec<SdkVersion> {
+        let mut v: Vec<SdkVersion> = versions
+            .iter()
+            .filter_map(|s| SdkVersion::parse(s))
+            .collect();
+        v.sort();
+        v
+    }
+
+    fn select(installed: &[SdkVersion], requested: &str, policy: &str) -> Option<String> {
+        let requested = SdkVersion::parse(requested).unwrap();
+        select_sdk(installed, &requested, policy, false).map(|s| s.raw.clone())
+    }
+
+    #[test]
+    fn test_sdk_roll_forward() {
+        let installed = sdks(&[
+            "6.0.428", "8.0.104", "8.0.110", "8.0.204", "8.0.303", "9.0.100",
+        ]);
+
+        assert_eq!(
+            select(&installed, "8.0.100", "latestPatch").as_deref(),
+            Some("8.0.110")
+        );
+        assert_eq!(
+            select(&installed, "8.0.104", "patch").as_deref(),
+            Some("8.0.104")
+        );
+        assert_eq!(
+            select(&installed, "8.0.105", "patch").as_deref(),
+            Some("8.0.110")
+        );
+        assert_eq!(
+            select(&installed, "8.0.104", "disable").as_deref(),
+            Some("8.0.104")
+        );
+        assert_eq!(select(&installed, "8.0.105", "disable"), None);
+        assert_eq!(select(&installed, "8.0.111", "latestPatch"), None);
+        assert_eq!(
+            select(&installed, "8.0.111", "feature").as_deref(),
+            Some("8.0.204")
+        );
+        assert_eq!(
+            select(&installed, "8.0.100", "latestFeature").as_deref(),
+            Some("8.0.303")
+        );
+        assert_eq!(select(&installed, "8.0.400", "minor"), None);
+        assert_eq!(
+            select(&installed, "8.0.400", "major").as_deref(),
+            Some("9.0.100")
+        );
+        assert_eq!(
+            select(&installed, "6.0.100", "latestMajor").as_deref(),
+            Some("9.0.100")
+        );
+        assert_eq!(select(&installed, "7.0.100", "latestMinor"), None);
+    }
+
+    #[test]
+    fn test_sdk_prerelease() {
+        let installed = sdks(&["9.0.100-rc.2.24474.11"]);
+        let requested = SdkVersion::parse("9.0.100-rc.1.24452.12").unwrap();
+        assert!(select_sdk(&installed, &requested, "latestPatch", true).is_some());
+        assert!(select_sdk(&installed, &requested, "latestPatch", false).is_none());
+    }
+
+    #[test]
+    fn test_go_toolchain_selection() {
+        let module = |go: &str, toolchain: Option<&str>| GoContext {
+            go_version: Some(go.into()),
+            toolchain: toolchain.map(str::to_string),
+            source: "go.mod".into(),
+        };
+        let select =
+            |setting: &str, go: &GoContext| go_toolchain_selection(setting, "1.21.5", Some(go));
+        let newer = module("1.22.1", None);
+
+        assert_eq!(
+            select("auto", &newer),
+            ("1.21.5".into(), Some("1.22.1".into()))
+        );
+        assert_eq!(
+            select("auto", &module("1.22.0", Some("1.22.3"))),
+            ("1.21.5".into(), Some("1.22.3".into()))
+        );
+        assert_eq!(
+            select("auto", &module("1.20", None)),
+            ("1.21.5".into(), None)
+        );
+        assert_eq!(select("local", &newer), ("1.21.5".into(), None));
+        assert_eq!(
+            select("go1.22.0+auto", &newer),
+            ("1.22.0".into(), Some("1.22.1".into()))
+        );
+        assert_eq!(select("go1.23.0+auto", &newer), ("1.23.0".into(), None));
+        assert_eq!(
+            select("go1.21.0", &module("1.23.0", None)),
+            ("1.21.0".into(), None)
+        );
+    }
+
+    #[test]
+    fn test_go_toolchain_cached() {
+        let cache = std::env::temp_dir().join(format!("vitals-gotoolchain-{}", std::process::id()));
+        let extracted = cache.join("golang.org/toolchain@v0.0.1-go1.22.3.linux-amd64");
+        let downloaded = cache.join("cache/download/golang.org/toolchain/@v");
+        std::fs::create_dir_all(&extracted).unwrap();
+        std::fs::create_dir_all(&downloaded).unwrap();
+        std::fs::write(downloaded.join("v0.0.1-go1.23.0.darwin-arm64.zip"), "").unwrap();
+
+        assert!(go_toolchain_cached(&cache, "1.22.3"));
+        assert!(go_toolchain_cached(&cache, "1.23.0"));
+        assert!(!go_toolchain_cached(&cache, "1.22.4"));
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_goprivate_covers() {
+        let patterns = ["github.com/acme", "*.corp.example.com"];
+        assert!(goprivate_covers(&patterns, "github.com/acme"));
+        assert!(goprivate_covers(&patterns, "github.com/acme/api/v2"));
+        assert!(goprivate_covers(&patterns, "git.corp.example.com/team/lib"));
+        assert!(!goprivate_covers(&patterns, "github.com/acmeco/api"));
+        assert!(!goprivate_covers(&patterns, "github.com"));
+        assert!(!goprivate_covers(&[], "github.com/acme/api"));
+    }
+
+    #[test]
+    fn test_unknown_tool_pin_skips() {
+        let req = |name: &str| RuntimeReq {
+            name: name.into(),
+            version_req: Some("2.15.0".into()),
+            source: ".tool-versions".into(),
+            extensions: Vec::new(),
+            conflicts: Vec::new(),
+        };
+        let dir = Path::new(".");
+        let config = VitalsConfig::default();
+
+        let unknown = check_one(&req("vitals-test-plugin"), dir, &config, &Platform::Debian);
+        assert_eq!(unknown.status, Status::Skip);
+        assert!(unknown.fix.is_none());
+
+        // awscli is probed as `aws`, so a missing one is a real failure.
+        assert!(TOOLS.contains(&("awscli", "aws")));
+        let mapped = check_one(
+            &req("awscli"),
+            Path::new("/nonexistent"),
+            &config,
+            &Platform::Debian,
+        );
+        assert_ne!(mapped.status, Status::Skip);
     }
 }
@@ -1,12 +1,37 @@
-use std::net::{TcpStream, ToSocketAddrs};
-use std::time::Duration;
-
-use crate::check::{CheckResult, Status};
+use super::Check;
+use super::probe::{self, Health, Target};
+use crate::check::{CheckResult, FixAction, Status};
 use crate::config::VitalsConfig;
 use crate::detect::{ProjectContext, ServiceReq};
+use crate::parallel;
 use crate::platform::Platform;
 use crate::version;
 
+pub struct ServiceCheck;
+
+impl Check for ServiceCheck {
+    fn id(&self) -> &'static str {
+        "services"
+    }
+
+    fn category(&self) -> &'static str {
+        "Services"
+    }
+
+    fn description(&self) -> &'static str {
+        "Docker running, Redis/Postgres/MySQL/MongoDB reachable"
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        platform: &Platform,
+    ) -> Vec<CheckResult> {
+        check(ctx, config, platform)
+    }
+}
+
 pub fn check(ctx: &ProjectContext, config: &VitalsConfig, platform: &Platform) -> Vec<CheckResult> {
     let mut results = Vec::new();
 
@@ -19,6 +44,7 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig, platform: &Platform) -
                 .unwrap_or_else(|| "unknown".into());
             results.push(CheckResult {
                 category: "Services".into(),
+                package: None,
                 name: "docker".into(),
                 status: Status::Pass,
                 found: format!("running (v{})", ver),
@@ -31,28 +57,32 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig, platform: &Platform) -
             if installed {
                 results.push(CheckResult {
                     category: "Services".into(),
+                    package: None,
                     name: "docker".into(),
                     status: Status::Fail,
                     found: "not running".into(),
                     expected: "running".into(),
-                    fix: Some("open -a Docker (macOS) or sudo systemctl start docker".into()),
+                    fix: Some(FixAction::manual(
+                        "open -a Docker (macOS) or sudo systemctl start docker",
+                    )),
                     details: vec![],
                 });
             } else {
                 results.push(CheckResult {
                     category: "Services".into(),
+                    package: None,
                     name: "docker".into(),
                     status: Status::Fail,
                     found: "not installed".into(),
                     expected: "installed & running".into(),
-                    fix: Some(platform.install_hint("docker")),
+                    fix: Some(FixAction::confirm(platform.install_hint("docker"))),
                     details: vec![],
                 });
             }
         }
     }
 
-    // ── Individual services (docker-compose + config) ───────────────────
+    // ── Individual services (docker-compose + .env URLs + config) ───────
     let mut service_reqs: Vec<ServiceReq> = ctx.services.clone();
 
     // Merge config-defined services.
@@ -65,33 +95,120 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig, platform: &Platform) -
     );
     merge_config_service(&mut service_reqs, &config.services.mysql, "mysql", 3306);
     merge_config_service(&mut service_reqs, &config.services.mongo, "mongo", 27017);
+    merge_config_service(
+        &mut service_reqs,
+        &config.services.rabbitmq,
+        "rabbitmq",
+        5672,
+    );
+    merge_config_service(
+        &mut service_reqs,
+        &config.services.memcached,
+        "memcached",
+        11211,
+    );
+    merge_config_service(
+        &mut service_reqs,
+        &config.services.elasticsearch,
+        "elasticsearch",
+        9200,
+    );
+    merge_config_service(&mut service_reqs, &config.services.minio, "minio", 9000);
+
+    results.extend(reconcile_env_urls(&mut service_reqs));
 
-    for svc in &service_reqs {
-        let reachable = is_reachable(&svc.host, svc.port);
-        if reachable {
+    // Each probe can block for the connect timeout; probe all at once.
+    let workers = config
+        .checks
+        .workers
+        .unwrap_or_else(parallel::default_workers);
+    let health = parallel::map(service_reqs.iter().collect(), workers, |svc| {
+        let detail = config.services.detail(&svc.kind);
+        let target = Target {
+            host: &svc.host,
+            port: svc.port,
+            user: svc
+                .user
+                .as_deref()
+                .or_else(|| detail.and_then(|d| d.user.as_deref())),
+            password: svc
+                .password
+                .as_deref()
+                .or_else(|| detail.and_then(|d| d.password.as_deref())),
+            database: svc
+                .database
+                .as_deref()
+                .or_else(|| detail.and_then(|d| d.database.as_deref())),
+        };
+        probe::probe(&svc.kind, &target)
+    });
+
+    for (svc, health) in service_reqs.iter().zip(health) {
+        let at = endpoint(svc);
+        let from_env = svc.source.starts_with(".env");
+        let mut details: Vec<String> = if from_env {
+            vec![format!("from {}", svc.source)]
+        } else {
+            vec![]
+        };
+
+        if let Health::Healthy { version, note } = health {
+            let found = match version {
+                Some(v) => format!("v{} on {}", v.trim_start_matches('v'), at),
+                None => format!("healthy on {}", at),
+            };
+            details.extend(note);
             results.push(CheckResult {
                 category: "Services".into(),
+                package: None,
                 name: svc.name.clone(),
                 status: Status::Pass,
-                found: format!("reachable on :{}", svc.port),
-                expected: format!("reachable on :{}", svc.port),
+                found,
+                expected: format!("healthy on {}", at),
                 fix: None,
-                details: vec![],
+                details,
+            });
+        } else if let Health::Unhealthy(reason) = health {
+            let fix = if from_env {
+                FixAction::manual(format!("check the connection URL in {}", svc.source))
+            } else if ctx.has_docker {
+                let name = svc.compose_service.as_deref().unwrap_or(&svc.kind);
+                FixAction::manual(format!("inspect: docker compose logs {}", name))
+            } else {
+                FixAction::manual(format!(
+                    "check {} logs and [services.{}] credentials in .vitals.toml",
+                    svc.name, svc.kind
+                ))
+            };
+            details.push(reason);
+            results.push(CheckResult {
+                category: "Services".into(),
+                package: None,
+                name: svc.name.clone(),
+                status: Status::Fail,
+                found: format!("unhealthy on {}", at),
+                expected: format!("healthy on {}", at),
+                fix: Some(fix),
+                details,
             });
         } else {
-            let fix = if ctx.has_docker {
-                format!("docker compose up -d {}", svc.name)
+            let fix = if ctx.has_docker && is_local(&svc.host) {
+                let name = svc.compose_service.as_deref().unwrap_or(&svc.kind);
+                FixAction::safe(format!("docker compose up -d {}", name), &ctx.dir)
+            } else if is_local(&svc.host) {
+                FixAction::confirm(platform.service_hint(&svc.kind))
             } else {
-                platform.service_hint(&svc.name)
+                FixAction::manual(format!("check network access to {}:{}", svc.host, svc.port))
             };
             results.push(CheckResult {
                 category: "Services".into(),
+                package: None,
                 name: svc.name.clone(),
                 status: Status::Fail,
-                found: format!("not reachable on :{}", svc.port),
-                expected: format!("reachable on :{}", svc.port),
+                found: format!("not reachable on {}", at),
+                expected: format!("healthy on {}", at),
                 fix: Some(fix),
-                details: vec![],
+                details,
             });
         }
     }
@@ -99,29 +216,87 @@ pub fn check(ctx: &ProjectContext, config: &VitalsConfig, platform: &Platform) -
     results
 }
 
+/// Services from `.env` URLs are what the app actually connects to, so they
+/// replace docker-compose / config entries of the same kind. If compose
+/// publishes a different port, warn — one of the two is stale.
+fn reconcile_env_urls(reqs: &mut Vec<ServiceReq>) -> Vec<CheckResult> {
+    let mut warnings = Vec::new();
+    let env_reqs: Vec<ServiceReq> = reqs
+        .iter()
+        .filter(|s| s.source.starts_with(".env"))
+        .cloned()
+        .collect();
+
+    for env in &env_reqs {
+        let mut i = 0;
+        while i < reqs.len() {
+            let other = &reqs[i];
+            if other.source.starts_with(".env") || other.kind != env.kind {
+                i += 1;
+                continue;
+            }
+            if other.port != env.port && is_local(&env.host) {
+                warnings.push(CheckResult {
+                    category: "Services".into(),
+                    package: None,
+                    name: env.name.clone(),
+                    status: Status::Warn,
+                    found: format!("{} uses :{}", env.source, env.port),
+                    expected: format!("{} publishes :{}", other.source, other.port),
+                    fix: Some(FixAction::manual(format!(
+                        "align the port in {} with {}",
+                        env.source, other.source
+                    ))),
+                    details: vec![],
+                });
+            }
+            let replaced = reqs.remove(i);
+            if replaced.compose_service.is_some() && is_local(&env.host) {
+                if let Some(e) = reqs
+                    .iter_mut()
+                    .find(|s| s.source == env.source && s.compose_service.is_none())
+                {
+                    e.compose_service = replaced.compose_service;
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
+fn is_local(host: &str) -> bool {
+    matches!(host, "localhost" | "127.0.0.1" | "::1" | "0.0.0.0")
+}
+
+/// ":5432" for local services, "db.internal:6543" otherwise.
+fn endpoint(svc: &ServiceReq) -> String {
+    if is_local(&svc.host) {
+        format!(":{}", svc.port)
+    } else {
+        format!("{}:{}", svc.host, svc.port)
+    }
+}
+
 fn merge_config_service(
     reqs: &mut Vec<ServiceReq>,
     detail: &Option<crate::config::ServiceDetail>,
-    name: &str,
+    kind: &str,
     default_port: u16,
 ) {
     if let Some(d) = detail {
-        if !reqs.iter().any(|s| s.name == name) {
+        if !reqs.iter().any(|s| s.kind == kind) {
             reqs.push(ServiceReq {
-                name: name.into(),
+                name: kind.into(),
+                kind: kind.into(),
                 host: d.host.clone().unwrap_or_else(|| "localhost".into()),
                 port: d.port.unwrap_or(default_port),
+                source: ".vitals.toml".into(),
+                user: None,
+                password: None,
+                database: None,
+                compose_service: None,
             });
         }
     }
 }
-
-fn is_reachable(host: &str, port: u16) -> bool {
-    let addr = format!("{}:{}", host, port);
-    if let Ok(mut addrs) = addr.to_socket_addrs() {
-        if let Some(addr) = addrs.next() {
-            return TcpStream::connect_timeout(&addr, Duration::from_secs(2)).is_ok();
-        }
-    }
-    false
-}
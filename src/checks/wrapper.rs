@@ -0,0 +1,192 @@
+use super::Check;
+use crate::check::{CheckResult, FixAction, Status};
+use crate::config::VitalsConfig;
+use crate::detect::{ProjectContext, WrapperInfo};
+use crate::platform::Platform;
+use crate::version;
+
+pub struct WrapperCheck;
+
+impl Check for WrapperCheck {
+    fn id(&self) -> &'static str {
+        "wrappers"
+    }
+
+    fn category(&self) -> &'static str {
+        "Build tools"
+    }
+
+    fn description(&self) -> &'static str {
+        "mvnw/gradlew present and executable, distribution as required and cached"
+    }
+
+    fn applies(&self, ctx: &ProjectContext, _config: &VitalsConfig) -> bool {
+        !ctx.wrappers.is_empty()
+    }
+
+    fn run(
+        &self,
+        ctx: &ProjectContext,
+        config: &VitalsConfig,
+        _platform: &Platform,
+    ) -> Vec<CheckResult> {
+        ctx.wrappers.iter().map(|w| check(w, ctx, config)).collect()
+    }
+}
+
+pub fn check(wrapper: &WrapperInfo, ctx: &ProjectContext, config: &VitalsConfig) -> CheckResult {
+    let script = wrapper
+        .script
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let required = match wrapper.tool.as_str() {
+        "gradle" => config.require.gradle.as_deref(),
+        _ => config.require.maven.as_deref(),
+    };
+    let expected = match required {
+        Some(req) => format!("{} {} (.vitals.toml)", title(&wrapper.tool), req),
+        None => "executable, distribution cached".into(),
+    };
+    let result = |status: Status, found: String, fix: Option<FixAction>| CheckResult {
+        category: "Build tools".into(),
+        package: None,
+        name: script.clone(),
+        status,
+        found,
+        expected: expected.clone(),
+        fix,
+        details: vec![],
+    };
+
+    if !wrapper.script_exists {
+        let fix = match wrapper.tool.as_str() {
+            "gradle" => "gradle wrapper",
+            _ => "mvn wrapper:wrapper",
+        };
+        return result(
+            Status::Fail,
+            "missing".into(),
+            Some(FixAction::safe(fix, &ctx.dir)),
+        );
+    }
+    if !wrapper.executable {
+        return result(
+            Status::Fail,
+            "not executable".into(),
+            Some(FixAction::safe(format!("chmod +x {}", script), &ctx.dir)),
+        );
+    }
+
+    let Some(found_version) = wrapper.version.as_deref() else {
+        let properties = wrapper
+            .properties
+            .strip_prefix(&ctx.dir)
+            .unwrap_or(&wrapper.properties)
+            .display()
+            .to_string();
+        return result(
+            Status::Warn,
+            "no distributionUrl".into(),
+            Some(FixAction::manual(format!(
+                "set distributionUrl in {}",
+                properties
+            ))),
+        );
+    };
+    let found = format!("{} {}", title(&wrapper.tool), found_version);
+
+    if let Some(req) = required {
+        if !version::meets_requirement(found_version, req) {
+            let fix = match wrapper.tool.as_str() {
+                "gradle" => format!(
+                    "./gradlew wrapper --gradle-version {}",
+                    version::min_version(req)
+                ),
+                _ => format!(
+                    "./mvnw wrapper:wrapper -Dmaven={}",
+                    version::min_version(req)
+                ),
+            };
+            let mut r = result(Status::Fail, found, Some(FixAction::confirm(fix)));
+            r.details.extend(wrapper.distribution_url.clone());
+            return r;
+        }
+    }
+
+    if !wrapper.cached {
+        // Any wrapper invocation downloads and unpacks the distribution.
+        return result(
+            Status::Warn,
+            format!("{} (not downloaded)", found),
+            Some(FixAction::safe(format!("./{} --version", script), &ctx.dir)),
+        );
+    }
+    result(Status::Pass, found, None)
+}
+
+fn title(tool: &str) -> &'static str {
+    match tool {
+        "gradle" => "Gradle",
+        _ => "Maven",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn wrapper(exists: bool, executable: bool, version: Option<&str>, cached: bool) -> WrapperInfo {
+        WrapperInfo {
+            tool: "gradle".into(),
+            script: PathBuf::from("/p/gradlew"),
+            script_exists: exists,
+            executable,
+            properties: PathBuf::from("/p/gradle/wrapper/gradle-wrapper.properties"),
+            distribution_url: version.map(|v| {
+                format!(
+                    "https://services.gradle.org/distributions/gradle-{}-bin.zip",
+                    v
+                )
+            }),
+            version: version.map(str::to_string),
+            cached,
+        }
+    }
+
+    #[test]
+    fn test_wrapper_states() {
+        let ctx = crate::detect::scan(std::path::Path::new("/nonexistent")).unwrap();
+        let mut config = VitalsConfig::default();
+        let status = |w: &WrapperInfo, config: &VitalsConfig| {
+            let r = check(w, &ctx, config);
+            (r.status, r.found)
+        };
+
+        assert_eq!(
+            status(&wrapper(false, false, Some("8.5"), true), &config),
+            (Status::Fail, "missing".into())
+        );
+        assert_eq!(
+            status(&wrapper(true, false, Some("8.5"), true), &config),
+            (Status::Fail, "not executable".into())
+        );
+        assert_eq!(
+            status(&wrapper(true, true, Some("8.5"), false), &config),
+            (Status::Warn, "Gradle 8.5 (not downloaded)".into())
+        );
+        assert_eq!(
+            status(&wrapper(true, true, Some("8.5"), true), &config),
+            (Status::Pass, "Gradle 8.5".into())
+        );
+
+        config.require.gradle = Some(">=8.7".into());
+        let r = check(&wrapper(true, true, Some("8.5"), true), &ctx, &config);
+        assert_eq!(r.status, Status::Fail);
+        assert_eq!(
+            r.fix.unwrap().command,
+            "./gradlew wrapper --gradle-version 8.7"
+        );
+    }
+}
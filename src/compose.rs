@@ -0,0 +1,660 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+
+// ── Types ───────────────────────────────────────────────────────────────────
+
+/// A docker compose project: the merged result of all compose files.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComposeProject {
+    /// Project name (`COMPOSE_PROJECT_NAME`, top-level `name:`, or directory).
+    pub name: String,
+    /// Compose files, in merge order.
+    pub files: Vec<PathBuf>,
+    /// Services enabled for the active profiles.
+    pub services: Vec<ComposeService>,
+}
+
+/// A single service from the compose file.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComposeService {
+    pub name: String,
+    pub image: Option<String>,
+    pub ports: Vec<PortMapping>,
+    pub profiles: Vec<String>,
+    pub depends_on: Vec<String>,
+    /// Whether a (non-disabled) healthcheck is defined.
+    pub healthcheck: bool,
+    /// Compose file the service was first defined in.
+    pub file: String,
+}
+
+/// A published port (`"127.0.0.1:8080:80/tcp"`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PortMapping {
+    pub host_ip: Option<String>,
+    pub published: u16,
+    pub target: u16,
+    pub protocol: String,
+}
+
+// ── Loading ─────────────────────────────────────────────────────────────────
+
+const DEFAULT_FILES: &[(&str, &[&str])] = &[
+    (
+        "compose.yaml",
+        &["compose.override.yaml", "compose.override.yml"],
+    ),
+    (
+        "compose.yml",
+        &["compose.override.yml", "compose.override.yaml"],
+    ),
+    (
+        "docker-compose.yaml",
+        &[
+            "docker-compose.override.yaml",
+            "docker-compose.override.yml",
+        ],
+    ),
+    (
+        "docker-compose.yml",
+        &[
+            "docker-compose.override.yml",
+            "docker-compose.override.yaml",
+        ],
+    ),
+];
+
+/// Find and parse the compose project in `dir`, honouring `COMPOSE_FILE`,
+/// override files, `COMPOSE_PROFILES`, and `${VAR}` interpolation. A compose
+/// file that can't be read or parsed is an error naming the file.
+pub fn load(dir: &Path) -> Result<Option<ComposeProject>> {
+    let dotenv = read_dotenv(dir);
+    let lookup = |key: &str| env::var(key).ok().or_else(|| dotenv.get(key).cloned());
+
+    let files = compose_files(dir, &lookup);
+    if files.is_empty() {
+        return Ok(None);
+    }
+
+    let mut sources = Vec::new();
+    for path in &files {
+        let content =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        sources.push((name, interpolate(&content, &lookup)));
+    }
+
+    let profiles: Vec<String> = lookup("COMPOSE_PROFILES")
+        .map(|p| p.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_default();
+
+    let mut project = parse(&sources, &profiles)?;
+    project.files = files;
+    if let Some(name) = lookup("COMPOSE_PROJECT_NAME") {
+        project.name = name;
+    }
+    if project.name.is_empty() {
+        project.name = dir
+            .canonicalize()
+            .ok()
+            .and_then(|d| d.file_name().map(|n| n.to_string_lossy().to_string()))
+            .unwrap_or_default();
+    }
+    project.name = normalize_project_name(&project.name);
+    Ok(Some(project))
+}
+
+fn compose_files(dir: &Path, lookup: &impl Fn(&str) -> Option<String>) -> Vec<PathBuf> {
+    if let Some(list) = lookup("COMPOSE_FILE") {
+        let sep = lookup("COMPOSE_PATH_SEPARATOR")
+            .unwrap_or_else(|| if cfg!(windows) { ";" } else { ":" }.to_string());
+        return list
+            .split(sep.as_str())
+            .filter(|f| !f.is_empty())
+            .map(|f| dir.join(f))
+            .filter(|p| p.is_file())
+            .collect();
+    }
+
+    for (base, overrides) in DEFAULT_FILES {
+        let path = dir.join(base);
+        if path.is_file() {
+            let mut files = vec![path];
+            if let Some(o) = overrides.iter().map(|o| dir.join(o)).find(|p| p.is_file()) {
+                files.push(o);
+            }
+            return files;
+        }
+    }
+    Vec::new()
+}
+
+/// Compose project names are lowercase `[a-z0-9_-]`.
+fn normalize_project_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+        .collect()
+}
+
+fn read_dotenv(dir: &Path) -> HashMap<String, String> {
+    fs::read_to_string(dir.join(".env"))
+        .map(|content| {
+            content
+                .lines()
+                .map(|l| l.trim())
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .filter_map(|l| {
+                    let l = l.strip_prefix("export ").unwrap_or(l);
+                    let (k, v) = l.split_once('=')?;
+                    let v = v.trim().trim_matches('"').trim_matches('\'');
+                    Some((k.trim().to_string(), v.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Expand `$VAR`, `${VAR}`, `${VAR:-default}`, `${VAR-default}`, `${VAR:?error}` and
+/// `${VAR?error}`; `$$` is a literal `$`.
+pub fn interpolate(content: &str, lookup: &impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+        match chars.peek().map(|(_, c)| *c) {
+            Some('$') => {
+                chars.next();
+                out.push('$');
+            }
+            Some('{') => {
+                let rest = &content[i + 2..];
+                let Some(end) = rest.find('}') else {
+                    out.push(c);
+                    continue;
+                };
+                let expr = &rest[..end];
+                // The operator follows the name; defaults and messages may
+                // contain any of the operator characters.
+                let name_len = expr
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(expr.len());
+                let (var, op) = expr.split_at(name_len);
+                let value = if let Some(default) = op.strip_prefix(":-") {
+                    lookup(var)
+                        .filter(|v| !v.is_empty())
+                        .unwrap_or_else(|| default.to_string())
+                } else if let Some(default) = op.strip_prefix('-') {
+                    lookup(var).unwrap_or_else(|| default.to_string())
+                } else {
+                    // `:?` and `?` stop compose when unset; nothing to expand.
+                    lookup(var).unwrap_or_default()
+                };
+                out.push_str(&value);
+                // Skip past the closing brace.
+                for (j, _) in chars.by_ref() {
+                    if j == i + 2 + end {
+                        break;
+                    }
+                }
+            }
+            Some(n) if n.is_ascii_alphabetic() || n == '_' => {
+                let rest = &content[i + 1..];
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                out.push_str(&lookup(&rest[..len]).unwrap_or_default());
+                for _ in 0..len {
+                    chars.next();
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// ── Parsing ─────────────────────────────────────────────────────────────────
+
+/// Parse and merge compose documents (`(file name, content)`, in order).
+pub fn parse(sources: &[(String, String)], active_profiles: &[String]) -> Result<ComposeProject> {
+    let mut name = String::new();
+    // Service name → (merged definition, defining file), in definition order.
+    let mut merged: Vec<(String, Mapping, String)> = Vec::new();
+
+    for (file, content) in sources {
+        let mut doc =
+            serde_yaml::from_str::<Value>(content).with_context(|| format!("invalid {}", file))?;
+        // `<<: *defaults` merge keys, common with `x-` extension fields.
+        doc.apply_merge()
+            .with_context(|| format!("invalid {}", file))?;
+        if let Some(n) = doc.get("name").and_then(|v| v.as_str()) {
+            name = n.to_string();
+        }
+        let Some(services) = doc.get("services").and_then(|v| v.as_mapping()) else {
+            continue;
+        };
+        for (key, def) in services {
+            let (Some(svc), Some(def)) = (key.as_str(), def.as_mapping()) else {
+                continue;
+            };
+            match merged.iter_mut().find(|(n, _, _)| n == svc) {
+                Some((_, base, _)) => merge_service(base, def),
+                None => merged.push((svc.to_string(), def.clone(), file.clone())),
+            }
+        }
+    }
+
+    let services = merged
+        .into_iter()
+        .map(|(name, def, file)| to_service(name, &def, file))
+        .filter(|svc| {
+            svc.profiles.is_empty() || svc.profiles.iter().any(|p| active_profiles.contains(p))
+        })
+        .collect();
+
+    Ok(ComposeProject {
+        name,
+        files: Vec::new(),
+        services,
+    })
+}
+
+/// Apply an override on top of a base service definition. Like compose,
+/// `ports` and similar lists are appended; everything else is replaced.
+fn merge_service(base: &mut Mapping, over: &Mapping) {
+    for (key, value) in over {
+        let append = matches!(key.as_str(), Some("ports" | "expose" | "profiles"));
+        match (append, base.get_mut(key)) {
+            (true, Some(Value::Sequence(existing))) => {
+                if let Value::Sequence(more) = value {
+                    existing.extend(more.iter().cloned());
+                }
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+fn to_service(name: String, def: &Mapping, file: String) -> ComposeService {
+    let get = |k: &str| def.get(Value::from(k));
+
+    let image = get("image").and_then(|v| v.as_str()).map(str::to_string);
+    let profiles = string_list(get("profiles"));
+    let depends_on = match get("depends_on") {
+        Some(Value::Mapping(m)) => m
+            .keys()
+            .filter_map(|k| k.as_str().map(str::to_string))
+            .collect(),
+        other => string_list(other),
+    };
+    let healthcheck = match get("healthcheck") {
+        Some(Value::Mapping(h)) => {
+            !h.get(Value::from("disable"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+                && !matches!(
+                    h.get(Value::from("test"))
+                        .and_then(|t| t.as_sequence())
+                        .and_then(|s| s.first())
+                        .and_then(|v| v.as_str()),
+                    Some("NONE")
+                )
+        }
+        _ => false,
+    };
+    let ports = get("ports")
+        .and_then(|v| v.as_sequence())
+        .map(|seq| seq.iter().flat_map(parse_port).collect())
+        .unwrap_or_default();
+
+    ComposeService {
+        name,
+        image,
+        ports,
+        profiles,
+        depends_on,
+        healthcheck,
+        file,
+    }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_sequence())
+        .map(|s| {
+            s.iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse one `ports:` entry (short or long syntax) into published mappings.
+/// Entries without a published port (`"80"`) are skipped — the host port is
+/// random and can't be checked.
+fn parse_port(value: &Value) -> Vec<PortMapping> {
+    match value {
+        // A bare number is a target port only; nothing is published.
+        Value::String(s) => parse_short_port(s),
+        Value::Mapping(m) => {
+            let get = |k: &str| m.get(Value::from(k));
+            let Some(target) = get("target").and_then(value_u16) else {
+                return Vec::new();
+            };
+            let published = match get("published") {
+                Some(Value::String(s)) => parse_range(s).map(|(lo, _)| lo),
+                Some(v) => value_u16(v),
+                None => None,
+            };
+            let Some(published) = published else {
+                return Vec::new();
+            };
+            vec![PortMapping {
+                host_ip: get("host_ip").and_then(|v| v.as_str()).map(str::to_string),
+                published,
+                target,
+                protocol: get("protocol")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("tcp")
+                    .to_string(),
+            }]
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn parse_short_port(spec: &str) -> Vec<PortMapping> {
+    let spec = spec.trim();
+    let (spec, protocol) = match spec.rsplit_once('/') {
+        Some((s, p)) => (s, p.to_string()),
+        None => (spec, "tcp".to_string()),
+    };
+
+    // "[::1]:8080:80" — bracketed IPv6 host IP.
+    let (host_ip, rest) = if let Some(v6) = spec.strip_prefix('[') {
+        match v6.split_once("]:") {
+            Some((ip, rest)) => (Some(ip.to_string()), rest),
+            None => return Vec::new(),
+        }
+    } else {
+        (None, spec)
+    };
+
+    let parts: Vec<&str> = rest.split(':').collect();
+    let (host_ip, published, target) = match (host_ip, parts.as_slice()) {
+        (None, [_target]) => return Vec::new(),
+        (None, [published, target]) => (None, *published, *target),
+        (None, [ip, published, target]) => (Some(ip.to_string()), *published, *target),
+        (Some(ip), [published, target]) => (Some(ip), *published, *target),
+        _ => return Vec::new(),
+    };
+    if published.is_empty() {
+        // "127.0.0.1::80" — random host port.
+        return Vec::new();
+    }
+
+    let (Some((p_lo, p_hi)), Some((t_lo, t_hi))) = (parse_range(published), parse_range(target))
+    else {
+        return Vec::new();
+    };
+
+    if t_lo == t_hi {
+        // A published range for a single target: docker picks one; assume the first.
+        return vec![PortMapping {
+            host_ip,
+            published: p_lo,
+            target: t_lo,
+            protocol,
+        }];
+    }
+    (t_lo..=t_hi)
+        .zip(p_lo..=p_hi)
+        .map(|(target, published)| PortMapping {
+            host_ip: host_ip.clone(),
+            published,
+            target,
+            protocol: protocol.clone(),
+        })
+        .collect()
+}
+
+fn parse_range(s: &str) -> Option<(u16, u16)> {
+    match s.split_once('-') {
+        Some((lo, hi)) => Some((lo.trim().parse().ok()?, hi.trim().parse().ok()?)),
+        None => {
+            let p = s.trim().parse().ok()?;
+            Some((p, p))
+        }
+    }
+}
+
+fn value_u16(v: &Value) -> Option<u16> {
+    match v {
+        Value::Number(n) => n.as_u64().and_then(|n| u16::try_from(n).ok()),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+// ── Service kinds ───────────────────────────────────────────────────────────
+
+/// Well-known images: (name fragment, service kind, default port).
+const KNOWN_IMAGES: &[(&str, &str, u16)] = &[
+    ("redis", "redis", 6379),
+    ("valkey", "redis", 6379),
+    ("postgis", "postgres", 5432),
+    ("timescaledb", "postgres", 5432),
+    ("postgres", "postgres", 5432),
+    ("mysql", "mysql", 3306),
+    ("mariadb", "mysql", 3306),
+    ("percona", "mysql", 3306),
+    ("mongo", "mongo", 27017),
+    ("rabbitmq", "rabbitmq", 5672),
+    ("memcached", "memcached", 11211),
+    ("elasticsearch", "elasticsearch", 9200),
+    ("opensearch", "elasticsearch", 9200),
+    ("minio", "minio", 9000),
+    ("mailpit", "mailpit", 1025),
+];
+
+/// Infer the service kind from an image reference like
+/// `docker.io/bitnami/postgresql:16`. Returns `(kind, default port)`.
+pub fn image_kind(image: &str) -> Option<(&'static str, u16)> {
+    let repo = image_repo(image);
+    let base = repo.rsplit('/').next().unwrap_or(&repo);
+    KNOWN_IMAGES
+        .iter()
+        .find(|(fragment, _, _)| base.contains(fragment))
+        .map(|(_, kind, port)| (*kind, *port))
+}
+
+/// Image reference without tag or digest: `redis:7-alpine` → `redis`.
+pub fn image_repo(image: &str) -> String {
+    let image = image.split('@').next().unwrap_or(image);
+    // A ':' after the last '/' is a tag; before it, a registry port.
+    let last_slash = image.rfind('/').map(|i| i + 1).unwrap_or(0);
+    match image[last_slash..].find(':') {
+        Some(i) => image[..last_slash + i].to_lowercase(),
+        None => image.to_lowercase(),
+    }
+}
+
+/// Image tag (`redis:7.2` → `7.2`), defaulting to `latest`.
+pub fn image_tag(image: &str) -> String {
+    let image = image.split('@').next().unwrap_or(image);
+    let last_slash = image.rfind('/').map(|i| i + 1).unwrap_or(0);
+    image[last_slash..]
+        .split_once(':')
+        .map(|(_, tag)| tag.to_string())
+        .unwrap_or_else(|| "latest".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `(host_ip or "", published, target)` for each mapping.
+    fn ports(spec: &str) -> Vec<(String, u16, u16)> {
+        parse_short_port(spec)
+            .into_iter()
+            .map(|m| (m.host_ip.unwrap_or_default(), m.published, m.target))
+            .collect()
+    }
+
+    #[test]
+    fn test_short_port_syntax() {
+        assert_eq!(ports("8080:80"), vec![("".into(), 8080, 80)]);
+        assert_eq!(
+            ports("127.0.0.1:5433:5432"),
+            vec![("127.0.0.1".into(), 5433, 5432)]
+        );
+        assert_eq!(ports("[::1]:6380:6379"), vec![("::1".into(), 6380, 6379)]);
+        assert_eq!(
+            ports("9000-9001:9000-9001"),
+            vec![("".into(), 9000, 9000), ("".into(), 9001, 9001)]
+        );
+        assert_eq!(ports("5432"), vec![]);
+        assert_eq!(ports("127.0.0.1::5432"), vec![]);
+        assert_eq!(parse_short_port("53:53/udp")[0].protocol, "udp");
+    }
+
+    #[test]
+    fn test_parse_and_merge() {
+        let base = r#"
+name: Shop
+services:
+  db:
+    image: postgres:16
+    ports: ["5432:5432"]
+    healthcheck:
+      test: ["CMD", "pg_isready"]
+  # redis mentioned in a comment must not create a service
+  api:
+    build: .
+    environment:
+      REDIS_HOST: redis
+    ports:
+      - target: 3000
+        published: "3000"
+        host_ip: 127.0.0.1
+    depends_on:
+      db:
+        condition: service_healthy
+  debug:
+    image: busybox
+    profiles: [debug]
+    ports: ["9999:9999"]
+"#;
+        let over = r#"
+services:
+  db:
+    ports: ["5433:5432"]
+"#;
+        let project = parse(
+            &[
+                ("compose.yaml".into(), base.into()),
+                ("compose.override.yaml".into(), over.into()),
+            ],
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(project.name, "Shop");
+        let names: Vec<&str> = project.services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["db", "api"]);
+
+        let db = &project.services[0];
+        assert_eq!(db.ports.len(), 2);
+        assert_eq!(db.ports[1].published, 5433);
+        assert!(db.healthcheck);
+
+        let api = &project.services[1];
+        assert_eq!(api.depends_on, vec!["db"]);
+        assert_eq!(api.ports[0].host_ip.as_deref(), Some("127.0.0.1"));
+        assert!(!api.healthcheck);
+
+        let with_debug = parse(&[("compose.yaml".into(), base.into())], &["debug".into()]).unwrap();
+        assert_eq!(with_debug.services.len(), 3);
+
+        let anchored = r#"
+x-db-defaults: &db-defaults
+  image: postgres:16
+  ports: ["5432:5432"]
+services:
+  db:
+    <<: *db-defaults
+    healthcheck:
+      test: pg_isready
+"#;
+        let project = parse(&[("compose.yaml".into(), anchored.into())], &[]).unwrap();
+        let db = &project.services[0];
+        assert_eq!(db.image.as_deref(), Some("postgres:16"));
+        assert_eq!(db.ports[0].published, 5432);
+        assert!(db.healthcheck);
+
+        let broken = parse(
+            &[("compose.yaml".into(), "services:\n  db: [\n".into())],
+            &[],
+        );
+        assert_eq!(broken.unwrap_err().to_string(), "invalid compose.yaml");
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let lookup = |k: &str| match k {
+            "PORT" => Some("6380".to_string()),
+            "EMPTY" => Some(String::new()),
+            "DB_PASSWORD" => Some("s3cret".to_string()),
+            _ => None,
+        };
+        assert_eq!(interpolate("${PORT}:6379", &lookup), "6380:6379");
+        assert_eq!(interpolate("${MISSING:-5432}:5432", &lookup), "5432:5432");
+        assert_eq!(interpolate("${EMPTY:-x} ${EMPTY-y}", &lookup), "x ");
+        assert_eq!(interpolate("$PORT $$HOME", &lookup), "6380 $HOME");
+        assert_eq!(
+            interpolate("${DB_PASSWORD:?must-be-set}", &lookup),
+            "s3cret"
+        );
+        assert_eq!(interpolate("${MISSING?must-be-set}", &lookup), "");
+        assert_eq!(
+            interpolate("${MISSING:-http://a-b:8080}", &lookup),
+            "http://a-b:8080"
+        );
+    }
+
+    #[test]
+    fn test_image_kind() {
+        assert_eq!(image_kind("redis:7-alpine"), Some(("redis", 6379)));
+        assert_eq!(
+            image_kind("docker.io/bitnami/postgresql:16"),
+            Some(("postgres", 5432))
+        );
+        assert_eq!(
+            image_kind("localhost:5000/team/mariadb"),
+            Some(("mysql", 3306))
+        );
+        assert_eq!(image_kind("quay.io/keycloak/keycloak:24"), None);
+        assert_eq!(
+            image_repo("localhost:5000/team/app:1.2"),
+            "localhost:5000/team/app"
+        );
+        assert_eq!(image_tag("localhost:5000/team/app:1.2"), "1.2");
+        assert_eq!(image_tag("redis"), "latest");
+    }
+}
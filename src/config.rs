@@ -1,23 +1,28 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::time::Duration;
 
 use anyhow::Result;
 use serde::Deserialize;
 
 // ── Config types ────────────────────────────────────────────────────────────
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct VitalsConfig {
     pub require: RequireConfig,
     pub ports: PortsConfig,
     pub services: ServicesConfig,
     pub env: EnvConfig,
+    pub checks: ChecksConfig,
+    pub workspace: WorkspaceConfig,
+    pub go: GoConfig,
     #[serde(default)]
     pub commands: Vec<CommandConfig>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct RequireConfig {
     pub node: Option<String>,
@@ -26,15 +31,25 @@ pub struct RequireConfig {
     pub go: Option<String>,
     pub ruby: Option<String>,
     pub java: Option<String>,
+    pub php: Option<String>,
+    pub dotnet: Option<String>,
+    pub elixir: Option<String>,
+    pub erlang: Option<String>,
+    pub bun: Option<String>,
+    pub deno: Option<String>,
+    /// Maven/Gradle version, checked against the wrapper distribution (or
+    /// the system `mvn`/`gradle` when there is no wrapper).
+    pub maven: Option<String>,
+    pub gradle: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct PortsConfig {
     pub check: Vec<u16>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct ServicesConfig {
     pub docker: Option<bool>,
@@ -42,25 +57,99 @@ pub struct ServicesConfig {
     pub postgres: Option<ServiceDetail>,
     pub mysql: Option<ServiceDetail>,
     pub mongo: Option<ServiceDetail>,
+    pub rabbitmq: Option<ServiceDetail>,
+    pub memcached: Option<ServiceDetail>,
+    pub elasticsearch: Option<ServiceDetail>,
+    pub minio: Option<ServiceDetail>,
+}
+
+impl ServicesConfig {
+    /// Config block for a service kind, if any.
+    pub fn detail(&self, kind: &str) -> Option<&ServiceDetail> {
+        match kind {
+            "redis" => self.redis.as_ref(),
+            "postgres" => self.postgres.as_ref(),
+            "mysql" => self.mysql.as_ref(),
+            "mongo" => self.mongo.as_ref(),
+            "rabbitmq" => self.rabbitmq.as_ref(),
+            "memcached" => self.memcached.as_ref(),
+            "elasticsearch" => self.elasticsearch.as_ref(),
+            "minio" => self.minio.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ServiceDetail {
     pub host: Option<String>,
     pub port: Option<u16>,
+    /// Credentials used by the protocol health probe.
+    pub user: Option<String>,
+    pub password: Option<String>,
+    pub database: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct EnvConfig {
     pub required: Vec<String>,
     pub example: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct CommandConfig {
     pub name: String,
     pub run: String,
+    /// Seconds before the command is killed (defaults to the check timeout).
+    pub timeout: Option<u64>,
+}
+
+/// Go settings every developer needs, checked against `go env`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GoConfig {
+    /// Module path patterns that must be covered by `GOPRIVATE`.
+    pub private: Vec<String>,
+    /// Flags that must be present in `GOFLAGS` (e.g., "-mod=mod").
+    pub flags: Vec<String>,
+}
+
+/// Extra monorepo members on top of those found in workspace manifests.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceConfig {
+    /// Member directory globs (e.g., "services/*").
+    pub members: Vec<String>,
+    /// Globs of directories that are never members.
+    pub exclude: Vec<String>,
+}
+
+/// Default per-check timeout, in seconds.
+pub const DEFAULT_CHECK_TIMEOUT: u64 = 30;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ChecksConfig {
+    /// Default timeout for every check, in seconds.
+    pub timeout: Option<u64>,
+    /// Maximum number of checks run concurrently.
+    pub workers: Option<usize>,
+    /// Per-check timeout overrides by check id, in seconds.
+    pub timeouts: HashMap<String, u64>,
+}
+
+impl ChecksConfig {
+    /// Timeout for the check with the given id.
+    pub fn timeout_for(&self, id: &str) -> Duration {
+        let secs = self
+            .timeouts
+            .get(id)
+            .copied()
+            .or(self.timeout)
+            .unwrap_or(DEFAULT_CHECK_TIMEOUT);
+        Duration::from_secs(secs)
+    }
 }
 
 // ── Loader ──────────────────────────────────────────────────────────────────
@@ -88,6 +177,14 @@ pub fn template() -> &'static str {
 # python = ">=3.11"
 # rust = ">=1.75"
 # go = ">=1.21"
+# php = ">=8.2"
+# dotnet = ">=8.0"
+# elixir = "~> 1.15"
+# erlang = ">=26"
+# bun = ">=1.1"
+# deno = ">=2.0"
+# maven = ">=3.9"
+# gradle = "8.7"
 
 # Ports that should be available
 [ports]
@@ -104,6 +201,9 @@ pub fn template() -> &'static str {
 # [services.postgres]
 # host = "localhost"
 # port = 5432
+# user = "app"          # optional: verify credentials, not just reachability
+# password = "secret"
+# database = "app_dev"
 
 # Environment variables
 [env]
@@ -114,5 +214,24 @@ pub fn template() -> &'static str {
 # [[commands]]
 # name = "db-migrations"
 # run = "npx prisma migrate status"
+# timeout = 60
+
+# Execution: checks run in parallel, each with a timeout (seconds)
+[checks]
+# timeout = 30
+# workers = 4
+
+# [checks.timeouts]
+# services = 10
+
+# Go settings every developer needs (checked against `go env`)
+[go]
+# private = ["github.com/acme/*"]
+# flags = ["-mod=mod"]
+
+# Monorepo members beyond pnpm/npm/Cargo/go.work workspaces
+[workspace]
+# members = ["services/*"]
+# exclude = ["services/legacy"]
 "#
 }
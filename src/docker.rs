@@ -0,0 +1,243 @@
+//! Minimal Docker Engine API client over the Unix socket.
+//!
+//! Only the two read-only endpoints vitals needs: listing a compose project's
+//! containers and inspecting one of them.
+
+use std::env;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+
+const TIMEOUT: Duration = Duration::from_secs(3);
+
+/// A container as reported by the Engine API.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Container {
+    pub id: String,
+    /// Container name without the leading `/`.
+    pub name: String,
+    /// `com.docker.compose.service` label.
+    pub service: Option<String>,
+    /// Image reference the container was created from (e.g., "postgres:16").
+    pub image: String,
+    /// "running", "restarting", "exited", "created", "paused" or "dead".
+    pub state: String,
+    /// "healthy", "unhealthy" or "starting" when a healthcheck is configured.
+    pub health: Option<String>,
+    pub restart_count: u64,
+    pub exit_code: Option<i64>,
+}
+
+/// Where the Engine API socket is, from `DOCKER_HOST` or the usual locations.
+///
+/// Errors when `DOCKER_HOST` points at a non-Unix endpoint (tcp://, ssh://).
+pub fn socket_path() -> Result<PathBuf, String> {
+    if let Ok(host) = env::var("DOCKER_HOST") {
+        if !host.is_empty() {
+            return host
+                .strip_prefix("unix://")
+                .map(PathBuf::from)
+                .ok_or_else(|| format!("DOCKER_HOST={} is not a unix socket", host));
+        }
+    }
+
+    let mut candidates = vec![PathBuf::from("/var/run/docker.sock")];
+    if let Ok(home) = env::var("HOME") {
+        // Docker Desktop (macOS) and rootless docker.
+        candidates.push(PathBuf::from(&home).join(".docker/run/docker.sock"));
+    }
+    if let Ok(runtime) = env::var("XDG_RUNTIME_DIR") {
+        candidates.push(PathBuf::from(runtime).join("docker.sock"));
+    }
+    let default = candidates[0].clone();
+    Ok(candidates
+        .into_iter()
+        .find(|p| p.exists())
+        .unwrap_or(default))
+}
+
+/// All containers (running or not) belonging to a compose project.
+pub fn project_containers(socket: &Path, project: &str) -> io::Result<Vec<Container>> {
+    // filters={"label":["com.docker.compose.project=<name>"]}, URL-encoded.
+    let path = format!(
+        "/containers/json?all=1&filters=%7B%22label%22%3A%5B%22com.docker.compose.project%3D{}%22%5D%7D",
+        project
+    );
+    let list = get(socket, &path)?;
+    let Some(items) = list.as_array() else {
+        return Err(invalid("expected a container list"));
+    };
+
+    let mut containers = Vec::new();
+    for item in items {
+        let Some(id) = item.get("Id").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        // Health and restart count are only in the inspect output.
+        let inspect = get(socket, &format!("/containers/{}/json", id))?;
+        containers.push(from_inspect(id, item, &inspect));
+    }
+    Ok(containers)
+}
+
+fn from_inspect(id: &str, summary: &Value, inspect: &Value) -> Container {
+    let str_at = |v: &Value, ptr: &str| v.pointer(ptr).and_then(|s| s.as_str()).map(str::to_string);
+
+    let name = str_at(inspect, "/Name")
+        .or_else(|| str_at(summary, "/Names/0"))
+        .unwrap_or_else(|| id.chars().take(12).collect());
+
+    Container {
+        id: id.to_string(),
+        name: name.trim_start_matches('/').to_string(),
+        service: str_at(summary, "/Labels/com.docker.compose.service")
+            .or_else(|| str_at(inspect, "/Config/Labels/com.docker.compose.service")),
+        image: str_at(inspect, "/Config/Image")
+            .or_else(|| str_at(summary, "/Image"))
+            .unwrap_or_default(),
+        state: str_at(inspect, "/State/Status")
+            .or_else(|| str_at(summary, "/State"))
+            .unwrap_or_else(|| "unknown".into()),
+        health: str_at(inspect, "/State/Health/Status"),
+        restart_count: inspect
+            .pointer("/RestartCount")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0),
+        exit_code: inspect.pointer("/State/ExitCode").and_then(|v| v.as_i64()),
+    }
+}
+
+/// `GET path` against the Engine API and parse the JSON body.
+#[cfg(unix)]
+fn get(socket: &Path, path: &str) -> io::Result<Value> {
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    // HTTP/1.0 keeps the daemon from chunking the response.
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: docker\r\nUser-Agent: vitals\r\nAccept: application/json\r\n\r\n",
+        path
+    );
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = Vec::new();
+    stream.take(8 * 1024 * 1024).read_to_end(&mut response)?;
+    let response = String::from_utf8_lossy(&response);
+
+    let status: u16 = response
+        .lines()
+        .next()
+        .filter(|l| l.starts_with("HTTP/"))
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid("not an HTTP response"))?;
+
+    let body = response
+        .split_once("\r\n\r\n")
+        .map(|(_, b)| b)
+        .unwrap_or_default();
+    if status != 200 {
+        let message = serde_json::from_str::<Value>(body)
+            .ok()
+            .and_then(|v| v.get("message")?.as_str().map(str::to_string))
+            .unwrap_or_default();
+        return Err(io::Error::other(format!("HTTP {} {}", status, message)));
+    }
+    serde_json::from_str(body).map_err(|e| invalid(&e.to_string()))
+}
+
+#[cfg(not(unix))]
+fn get(_socket: &Path, _path: &str) -> io::Result<Value> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the Docker Engine API is only supported over Unix sockets",
+    ))
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+#[cfg(all(test, unix))]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::os::unix::net::UnixListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    /// Serve canned Engine API responses (request path prefix → JSON body)
+    /// on a fresh Unix socket until the test process exits.
+    pub(crate) fn fake_engine(routes: Vec<(&'static str, String)>) -> PathBuf {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let socket = env::temp_dir().join(format!(
+            "vitals-engine-{}-{}.sock",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_file(&socket);
+        let listener = UnixListener::bind(&socket).unwrap();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut line = String::new();
+                BufReader::new(&stream).read_line(&mut line).unwrap();
+                let path = line.split_whitespace().nth(1).unwrap_or_default();
+                let response = match routes.iter().find(|(p, _)| path.starts_with(p)) {
+                    Some((_, body)) => format!("HTTP/1.0 200 OK\r\n\r\n{}", body),
+                    None => "HTTP/1.0 404 Not Found\r\n\r\n{\"message\":\"no such container\"}"
+                        .to_string(),
+                };
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        socket
+    }
+
+    #[test]
+    fn test_project_containers() {
+        let socket = fake_engine(vec![
+            (
+                "/containers/json",
+                r#"[{"Id":"abc123","Names":["/shop-db-1"],"Image":"postgres:15","State":"running",
+                    "Labels":{"com.docker.compose.project":"shop","com.docker.compose.service":"db"}}]"#
+                    .into(),
+            ),
+            (
+                "/containers/abc123/json",
+                r#"{"Name":"/shop-db-1","RestartCount":4,"Config":{"Image":"postgres:15"},
+                    "State":{"Status":"running","ExitCode":0,"Health":{"Status":"unhealthy"}}}"#
+                    .into(),
+            ),
+        ]);
+
+        let containers = project_containers(&socket, "shop").unwrap();
+        assert_eq!(
+            containers,
+            vec![Container {
+                id: "abc123".into(),
+                name: "shop-db-1".into(),
+                service: Some("db".into()),
+                image: "postgres:15".into(),
+                state: "running".into(),
+                health: Some("unhealthy".into()),
+                restart_count: 4,
+                exit_code: Some(0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_error_status() {
+        let socket = fake_engine(vec![]);
+        let err = project_containers(&socket, "shop").unwrap_err();
+        assert!(err.to_string().contains("404"));
+    }
+}
@@ -0,0 +1,300 @@
+use std::io::{self, BufRead, Write};
+use std::process::Command;
+
+use colored::*;
+
+use crate::check::{CheckResult, FixAction, Safety, Status};
+use crate::detect::ProjectContext;
+
+/// What happened to a single fix action.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// Command ran and exited 0.
+    Applied,
+    /// Command ran and failed.
+    Failed(String),
+    /// User declined (or stdin was not interactive).
+    Declined,
+    /// Advice only — nothing to run.
+    Manual,
+}
+
+/// A fix action attempted for one or more failing checks.
+#[derive(Debug, Clone)]
+pub struct Attempt {
+    pub action: FixAction,
+    /// (category, name) of every check this action addresses. Names of
+    /// workspace member checks carry the member (`node_modules (apps/web)`).
+    pub checks: Vec<(String, String)>,
+    pub outcome: Outcome,
+}
+
+/// Whether any failing or warning result has a fix that [`apply`] can run.
+pub fn fixable(results: &[CheckResult]) -> bool {
+    results.iter().any(|r| {
+        matches!(r.status, Status::Fail | Status::Warn)
+            && r.fix.as_ref().is_some_and(FixAction::is_runnable)
+    })
+}
+
+/// Run the remediations attached to failing and warning results.
+///
+/// Safe actions run without asking when `yes` is set. Actions marked
+/// [`Safety::Confirm`] (installs, `sudo`, `kill`) always ask, and manual
+/// hints are only listed.
+pub fn apply(results: &[CheckResult], ctx: &ProjectContext, yes: bool) -> Vec<Attempt> {
+    let mut attempts: Vec<Attempt> = Vec::new();
+
+    for result in results {
+        if !matches!(result.status, Status::Fail | Status::Warn) {
+            continue;
+        }
+        let Some(action) = &result.fix else {
+            continue;
+        };
+        let key = (result.category.clone(), label(result));
+        // The same command can fix several checks; only run it once.
+        if let Some(prev) = attempts.iter_mut().find(|a| a.action == *action) {
+            prev.checks.push(key);
+            continue;
+        }
+        attempts.push(Attempt {
+            action: action.clone(),
+            checks: vec![key],
+            outcome: Outcome::Manual,
+        });
+    }
+
+    println!();
+    println!("  {}", "Fixes".bold().underline());
+
+    if attempts.is_empty() {
+        println!("  {} Nothing to fix.", "○".dimmed());
+        return attempts;
+    }
+
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+
+    for attempt in &mut attempts {
+        let action = &attempt.action;
+        let label = attempt
+            .checks
+            .iter()
+            .map(|(_, name)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if !action.is_runnable() {
+            println!(
+                "  {} {:<14} {} {}",
+                "○".dimmed(),
+                label,
+                "manual:".dimmed(),
+                action.command
+            );
+            attempt.outcome = Outcome::Manual;
+            continue;
+        }
+
+        let run = if action.safety == Safety::Safe && yes {
+            true
+        } else {
+            confirm(&mut input, &label, action)
+        };
+        if !run {
+            println!("  {} {:<14} skipped", "○".dimmed(), label);
+            attempt.outcome = Outcome::Declined;
+            continue;
+        }
+
+        println!(
+            "  {} {:<14} {}",
+            "→".dimmed(),
+            label,
+            action.command.yellow()
+        );
+        attempt.outcome = execute(action, ctx);
+        match &attempt.outcome {
+            Outcome::Applied => println!("  {} {:<14} done", "✓".green().bold(), label),
+            Outcome::Failed(why) => {
+                println!("  {} {:<14} {}", "✗".red().bold(), label, why.red())
+            }
+            _ => {}
+        }
+    }
+
+    attempts
+}
+
+/// Compare re-run results against the checks we tried to fix and print
+/// which now pass. Returns the number of checks that were fixed.
+pub fn report(attempts: &[Attempt], rerun: &[CheckResult]) -> usize {
+    let targeted: Vec<&(String, String)> = attempts
+        .iter()
+        .filter(|a| matches!(a.outcome, Outcome::Applied | Outcome::Failed(_)))
+        .flat_map(|a| a.checks.iter())
+        .collect();
+
+    if targeted.is_empty() {
+        return 0;
+    }
+
+    println!();
+    println!("  {}", "Re-check".bold().underline());
+
+    let mut fixed = 0;
+    for (category, name) in targeted {
+        let now = rerun
+            .iter()
+            .find(|r| &r.category == category && &label(r) == name);
+        match now.map(|r| &r.status) {
+            Some(Status::Pass) | None => {
+                fixed += 1;
+                println!(
+                    "  {} {:<14} {}",
+                    "✓".green().bold(),
+                    name,
+                    "now passes".green()
+                );
+            }
+            Some(_) => {
+                let found = now.map(|r| r.found.as_str()).unwrap_or_default();
+                println!(
+                    "  {} {:<14} {}",
+                    "✗".red().bold(),
+                    name,
+                    format!("still failing ({})", found).red()
+                );
+            }
+        }
+    }
+    fixed
+}
+
+fn label(result: &CheckResult) -> String {
+    match &result.package {
+        Some(package) => format!("{} ({})", result.name, package),
+        None => result.name.clone(),
+    }
+}
+
+fn confirm(input: &mut impl BufRead, label: &str, action: &FixAction) -> bool {
+    let sudo = if action.sudo { " (requires sudo)" } else { "" };
+    print!(
+        "  {} {:<14} run `{}`{}? [y/N] ",
+        "?".cyan().bold(),
+        label,
+        action.command,
+        sudo
+    );
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    match input.read_line(&mut answer) {
+        // EOF (non-interactive): never run without an explicit yes.
+        Ok(0) | Err(_) => {
+            println!();
+            false
+        }
+        Ok(_) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+    }
+}
+
+fn execute(action: &FixAction, ctx: &ProjectContext) -> Outcome {
+    let cwd = action.cwd.as_deref().unwrap_or(&ctx.dir);
+    match Command::new("sh")
+        .arg("-c")
+        .arg(&action.command)
+        .current_dir(cwd)
+        .status()
+    {
+        Ok(status) if status.success() => Outcome::Applied,
+        Ok(status) => Outcome::Failed(format!("exit {}", status.code().unwrap_or(-1))),
+        Err(e) => Outcome::Failed(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(package: Option<&str>, name: &str, status: Status) -> CheckResult {
+        CheckResult {
+            category: "Dependencies".into(),
+            package: package.map(str::to_string),
+            name: name.into(),
+            status,
+            found: "missing".into(),
+            expected: "installed".into(),
+            fix: None,
+            details: vec![],
+        }
+    }
+
+    fn check(name: &str) -> (String, String) {
+        ("Dependencies".to_string(), name.to_string())
+    }
+
+    #[test]
+    fn test_apply_runs_shared_fix_once() {
+        let dir = std::env::temp_dir().join("vitals-fix-apply");
+        std::fs::create_dir_all(&dir).unwrap();
+        let ctx = crate::detect::scan(&dir).unwrap();
+        let install = FixAction::safe("true", &dir);
+        let with_fix = |mut r: CheckResult, fix: &FixAction| {
+            r.fix = Some(fix.clone());
+            r
+        };
+        let results = vec![
+            with_fix(result(None, "node_modules", Status::Fail), &install),
+            with_fix(
+                result(Some("apps/web"), "node_modules", Status::Warn),
+                &install,
+            ),
+            with_fix(result(None, "vendor", Status::Pass), &install),
+            with_fix(
+                result(None, ".env", Status::Warn),
+                &FixAction::manual("copy .env.example to .env"),
+            ),
+        ];
+        assert!(fixable(&results));
+        assert!(!fixable(&results[2..]));
+
+        let attempts = apply(&results, &ctx, true);
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(
+            attempts[0].checks,
+            vec![check("node_modules"), check("node_modules (apps/web)")]
+        );
+        assert_eq!(attempts[0].outcome, Outcome::Applied);
+        assert_eq!(attempts[1].checks, vec![check(".env")]);
+        assert_eq!(attempts[1].outcome, Outcome::Manual);
+    }
+
+    #[test]
+    fn test_report_rechecks_attempted_fixes() {
+        let attempt = |checks: Vec<(String, String)>, outcome: Outcome| Attempt {
+            action: FixAction::manual("fix"),
+            checks,
+            outcome,
+        };
+        let attempts = vec![
+            attempt(
+                vec![check("node_modules"), check("node_modules (apps/web)")],
+                Outcome::Applied,
+            ),
+            attempt(vec![check("venv")], Outcome::Failed("exit 1".into())),
+            attempt(vec![check("gems")], Outcome::Declined),
+        ];
+        let rerun = vec![
+            result(None, "node_modules", Status::Pass),
+            result(Some("apps/web"), "node_modules", Status::Warn),
+            result(None, "gems", Status::Fail),
+        ];
+        // node_modules now passes and venv no longer reports; the declined
+        // gems fix isn't re-checked.
+        assert_eq!(report(&attempts, &rerun), 2);
+        assert_eq!(report(&attempts[2..], &rerun), 0);
+    }
+}
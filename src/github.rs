@@ -0,0 +1,367 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::check::{CheckResult, Status};
+use crate::detect::ProjectContext;
+
+/// Whether we're running inside a GitHub Actions job.
+pub fn is_actions() -> bool {
+    env::var_os("GITHUB_ACTIONS").is_some()
+}
+
+/// Print `::error` / `::warning` workflow commands for failed and warned checks.
+pub fn print_annotations(results: &[CheckResult], ctx: &ProjectContext) {
+    for line in annotations(results, ctx) {
+        println!("{}", line);
+    }
+}
+
+/// Build workflow command lines for every failed or warned check.
+pub fn annotations(results: &[CheckResult], ctx: &ProjectContext) -> Vec<String> {
+    let workspace = env::var_os("GITHUB_WORKSPACE").map(PathBuf::from);
+
+    results
+        .iter()
+        .filter_map(|result| {
+            let level = match result.status {
+                Status::Fail => "error",
+                Status::Warn => "warning",
+                _ => return None,
+            };
+
+            // Member results point at files in the member's directory.
+            let project = result
+                .package
+                .as_deref()
+                .and_then(|p| ctx.members.iter().find(|m| m.name == p))
+                .map_or(ctx, |m| &m.project);
+
+            let mut props = Vec::new();
+            if let Some((file, line)) = locate(result, project) {
+                props.push(format!(
+                    "file={}",
+                    escape_property(&display_path(&file, &ctx.dir, workspace.as_deref()))
+                ));
+                if let Some(line) = line {
+                    props.push(format!("line={}", line));
+                }
+            }
+            props.push(format!(
+                "title={}",
+                escape_property(&format!(
+                    "vitals: {} / {}",
+                    result.category,
+                    check_name(result)
+                ))
+            ));
+
+            Some(format!(
+                "::{} {}::{}",
+                level,
+                props.join(","),
+                escape_data(&message(result))
+            ))
+        })
+        .collect()
+}
+
+/// Append a Markdown results table to `$GITHUB_STEP_SUMMARY`, if set.
+pub fn write_step_summary(results: &[CheckResult]) -> Result<()> {
+    let Some(path) = env::var_os("GITHUB_STEP_SUMMARY") else {
+        return Ok(());
+    };
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(step_summary(results).as_bytes())?;
+    Ok(())
+}
+
+/// Render results as a Markdown table for the job summary.
+pub fn step_summary(results: &[CheckResult]) -> String {
+    let passed = results.iter().filter(|r| r.status == Status::Pass).count();
+    let failed = results.iter().filter(|r| r.status == Status::Fail).count();
+
+    let mut md = String::from("### vitals — project health check\n\n");
+    if failed == 0 {
+        md.push_str(&format!("All {} checks passed\n\n", passed));
+    } else {
+        md.push_str(&format!(
+            "{}/{} checks passed — {} {}\n\n",
+            passed,
+            passed + failed,
+            failed,
+            if failed == 1 {
+                "issue found"
+            } else {
+                "issues found"
+            }
+        ));
+    }
+
+    md.push_str("| | Category | Check | Found | Expected | Fix |\n");
+    md.push_str("|---|---|---|---|---|---|\n");
+    for result in results {
+        let icon = match result.status {
+            Status::Pass => "✅",
+            Status::Fail => "❌",
+            Status::Warn => "⚠️",
+            Status::Skip => "⏭️",
+        };
+        md.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            icon,
+            escape_cell(&result.category),
+            escape_cell(&check_name(result)),
+            escape_cell(&result.found),
+            escape_cell(&result.expected),
+            result
+                .fix
+                .as_ref()
+                .map(|f| format!("`{}`", escape_cell(&f.command)))
+                .unwrap_or_default(),
+        ));
+    }
+    md.push('\n');
+    md
+}
+
+/// Check name, qualified with the workspace member in monorepos.
+fn check_name(result: &CheckResult) -> String {
+    match &result.package {
+        Some(package) => format!("{} ({})", result.name, package),
+        None => result.name.clone(),
+    }
+}
+
+fn message(result: &CheckResult) -> String {
+    let mut msg = format!("{}: found {}", result.name, result.found);
+    if !result.expected.is_empty() {
+        msg.push_str(&format!(", expected {}", result.expected));
+    }
+    if let Some(fix) = &result.fix {
+        msg.push_str(&format!("\nFix: {}", fix));
+    }
+    for detail in &result.details {
+        msg.push_str(&format!("\n{}", detail));
+    }
+    msg
+}
+
+/// Find the project file (and line, if known) that caused a requirement.
+fn locate(result: &CheckResult, ctx: &ProjectContext) -> Option<(PathBuf, Option<usize>)> {
+    match result.category.as_str() {
+        "Runtime" => {
+            let req = ctx.runtimes.iter().find(|r| r.name == result.name)?;
+            // Sources look like ".nvmrc" or "package.json engines".
+            let file = ctx.dir.join(req.source.split_whitespace().next()?);
+            if !file.is_file() {
+                return None;
+            }
+            let needle = req
+                .version_req
+                .as_deref()
+                .map(|v| v.trim_start_matches(['>', '<', '=', '~', '^', ' ']));
+            let line = needle.and_then(|n| find_line(&file, n));
+            Some((file, line))
+        }
+        "Dependencies" => {
+            let manifests: &[&str] = match result.name.as_str() {
+                "node_modules" => &["package.json"],
+                "virtualenv" => &["pyproject.toml", "requirements.txt", "Pipfile", "setup.py"],
+                "vendor" => &["composer.lock", "composer.json", "go.mod"],
+                "go modules" => &["go.mod"],
+                "gems" => &["Gemfile.lock", "Gemfile"],
+                "deps" | "_build" => &["mix.lock", "mix.exs"],
+                "deno cache" => &["deno.lock", "deno.json", "deno.jsonc"],
+                _ => &[],
+            };
+            manifests
+                .iter()
+                .map(|m| ctx.dir.join(m))
+                .find(|p| p.is_file())
+                .map(|p| (p, None))
+        }
+        "Lockfiles" => {
+            // Drift is in the manifest; a stray lockfile points at itself.
+            let file = match ctx.lockfiles.iter().find(|l| l.lockfile == result.name) {
+                Some(lock) => ctx.dir.join(&lock.manifest),
+                None => ctx.dir.join(&result.name),
+            };
+            file.is_file().then_some((file, None))
+        }
+        "Services" | "Containers" => {
+            let project = ctx.compose.as_ref()?;
+            // Published ports are named "api:8080"; annotate the compose service.
+            let service = ctx
+                .services
+                .iter()
+                .find(|s| s.name == result.name)
+                .and_then(|s| s.compose_service.as_deref())
+                .unwrap_or(&result.name);
+            let defined_in = project
+                .services
+                .iter()
+                .find(|s| s.name == service)
+                .map(|s| s.file.as_str());
+            let file = project
+                .files
+                .iter()
+                .find(|f| f.file_name().and_then(|n| n.to_str()) == defined_in)
+                .or_else(|| project.files.first())?
+                .clone();
+            let line = find_line(&file, &format!("{}:", service));
+            Some((file, line))
+        }
+        "Build tools" => {
+            let wrapper = ctx.wrappers.iter().find(|w| {
+                w.script
+                    .file_name()
+                    .is_some_and(|n| n == result.name.as_str())
+            })?;
+            let file = wrapper.properties.clone();
+            if !file.is_file() {
+                return None;
+            }
+            let line = find_line(&file, "distributionUrl");
+            Some((file, line))
+        }
+        "Environment" => ctx.env.example_file.clone().map(|p| (p, None)),
+        _ => None,
+    }
+}
+
+fn find_line(path: &Path, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return None;
+    }
+    fs::read_to_string(path)
+        .ok()?
+        .lines()
+        .position(|l| l.contains(needle))
+        .map(|i| i + 1)
+}
+
+/// Annotation paths must be relative to the repository root.
+fn display_path(file: &Path, project_dir: &Path, workspace: Option<&Path>) -> String {
+    let workspace = workspace.and_then(|w| w.canonicalize().ok());
+    let rel = workspace
+        .as_deref()
+        .and_then(|w| file.strip_prefix(w).ok())
+        .or_else(|| file.strip_prefix(project_dir).ok())
+        .unwrap_or(file);
+    rel.to_string_lossy().replace('\\', "/")
+}
+
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+fn escape_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::FixAction;
+
+    fn result(category: &str, name: &str, status: Status) -> CheckResult {
+        CheckResult {
+            category: category.into(),
+            package: None,
+            name: name.into(),
+            status,
+            found: "not found".into(),
+            expected: String::new(),
+            fix: None,
+            details: vec![],
+        }
+    }
+
+    #[test]
+    fn test_annotations_locate_files() {
+        let dir = std::env::temp_dir().join(format!("vitals-github-{}", std::process::id()));
+        fs::create_dir_all(dir.join("gradle/wrapper")).unwrap();
+        fs::write(
+            dir.join("package.json"),
+            "{\n  \"name\": \"app\",\n  \"engines\": { \"node\": \">=18\" },\n  \"dependencies\": { \"left-pad\": \"^1.3.0\" }\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("package-lock.json"),
+            "{\"lockfileVersion\": 3, \"packages\": {}}",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("compose.yaml"),
+            "services:\n  api:\n    image: app\n  redis:\n    image: redis:7\n    ports:\n      - \"6379:6379\"\n",
+        )
+        .unwrap();
+        fs::write(dir.join("build.gradle"), "plugins { id 'java' }\n").unwrap();
+        fs::write(dir.join("gradlew"), "#!/bin/sh\n").unwrap();
+        fs::write(
+            dir.join("gradle/wrapper/gradle-wrapper.properties"),
+            "distributionBase=GRADLE_USER_HOME\ndistributionUrl=https\\://services.gradle.org/distributions/gradle-8.5-bin.zip\n",
+        )
+        .unwrap();
+        let ctx = crate::detect::scan(&dir).unwrap();
+
+        let mut node = result("Runtime", "node", Status::Fail);
+        node.expected = ">=18 (package.json engines)".into();
+        node.fix = Some(FixAction::manual("nvm install 18"));
+        let results = vec![
+            node,
+            result("Lockfiles", "package-lock.json", Status::Warn),
+            result("Services", "redis", Status::Fail),
+            result("Build tools", "gradlew", Status::Fail),
+            result("Runtime", "python", Status::Pass),
+        ];
+        let lines = annotations(&results, &ctx);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            lines,
+            vec![
+                "::error file=package.json,line=3,title=vitals%3A Runtime / node::node: found not found, expected >=18 (package.json engines)%0AFix: nvm install 18",
+                "::warning file=package.json,title=vitals%3A Lockfiles / package-lock.json::package-lock.json: found not found",
+                "::error file=compose.yaml,line=4,title=vitals%3A Services / redis::redis: found not found",
+                "::error file=gradle/wrapper/gradle-wrapper.properties,line=2,title=vitals%3A Build tools / gradlew::gradlew: found not found",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_step_summary() {
+        let mut fail = result("Runtime", "node", Status::Fail);
+        fail.package = Some("packages/web".into());
+        fail.expected = ">=18 | <21".into();
+        fail.fix = Some(FixAction::manual("nvm install 20"));
+        let results = vec![result("Runtime", "python", Status::Pass), fail];
+
+        let md = step_summary(&results);
+        assert!(md.contains("1/2 checks passed — 1 issue found"));
+        assert!(md.contains("| ✅ | Runtime | python | not found |  |  |\n"));
+        assert!(md.contains(
+            "| ❌ | Runtime | node (packages/web) | not found | >=18 \\| <21 | `nvm install 20` |\n"
+        ));
+
+        let md = step_summary(&results[..1]);
+        assert!(md.contains("All 1 checks passed"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_data("50%\nnext"), "50%25%0Anext");
+        assert_eq!(escape_property("a: b, c"), "a%3A b%2C c");
+        assert_eq!(escape_cell("a|b"), "a\\|b");
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::check::{CheckResult, Status};
+
+/// Render check results as a JUnit XML report.
+///
+/// Each category becomes a `<testsuite>` and each result a `<testcase>`.
+/// Failures carry found/expected/fix in the message, skips become
+/// `<skipped>`, and detail lines go into `<system-out>`.
+pub fn render(results: &[CheckResult]) -> String {
+    // Group by category, preserving the order categories were run in.
+    let mut suites: Vec<(&str, Vec<&CheckResult>)> = Vec::new();
+    for result in results {
+        match suites.iter_mut().find(|(c, _)| *c == result.category) {
+            Some((_, cases)) => cases.push(result),
+            None => suites.push((&result.category, vec![result])),
+        }
+    }
+
+    let count = |status: Status| results.iter().filter(|r| r.status == status).count();
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"vitals\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" errors=\"0\">",
+        results.len(),
+        count(Status::Fail),
+        count(Status::Skip),
+    );
+
+    for (category, cases) in &suites {
+        let failures = cases.iter().filter(|r| r.status == Status::Fail).count();
+        let skipped = cases.iter().filter(|r| r.status == Status::Skip).count();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" errors=\"0\">",
+            escape(category),
+            cases.len(),
+            failures,
+            skipped,
+        );
+
+        for case in cases {
+            let _ = write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"vitals.{}\"",
+                escape(&case_name(case)),
+                escape(category),
+            );
+
+            let out = system_out(case);
+            if case.status == Status::Pass && out.is_empty() {
+                xml.push_str("/>\n");
+                continue;
+            }
+            xml.push_str(">\n");
+
+            match case.status {
+                Status::Fail => {
+                    let _ = writeln!(
+                        xml,
+                        "      <failure message=\"{}\" type=\"{}\">{}</failure>",
+                        escape(&failure_message(case)),
+                        escape(&case.category),
+                        escape(&failure_body(case)),
+                    );
+                }
+                Status::Skip => {
+                    let _ = writeln!(xml, "      <skipped message=\"{}\"/>", escape(&case.found));
+                }
+                Status::Pass | Status::Warn => {}
+            }
+
+            if !out.is_empty() {
+                let _ = writeln!(xml, "      <system-out>{}</system-out>", escape(&out));
+            }
+            xml.push_str("    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Write a JUnit XML report to `path`.
+pub fn write(path: &Path, results: &[CheckResult]) -> Result<()> {
+    fs::write(path, render(results))?;
+    Ok(())
+}
+
+fn failure_message(result: &CheckResult) -> String {
+    let mut msg = format!("found {}, expected {}", result.found, result.expected);
+    if let Some(fix) = &result.fix {
+        msg.push_str(&format!(" — fix: {}", fix));
+    }
+    msg
+}
+
+fn failure_body(result: &CheckResult) -> String {
+    let mut body = format!("found: {}\nexpected: {}", result.found, result.expected);
+    if let Some(fix) = &result.fix {
+        body.push_str(&format!("\nfix: {}", fix));
+    }
+    body
+}
+
+fn system_out(result: &CheckResult) -> String {
+    let mut lines = Vec::new();
+    if result.status == Status::Warn {
+        lines.push(format!(
+            "warning: found {}, expected {}",
+            result.found, result.expected
+        ));
+    }
+    lines.extend(result.details.iter().cloned());
+    lines.join("\n")
+}
+
+/// Test case name, qualified with the workspace member in monorepos.
+fn case_name(result: &CheckResult) -> String {
+    match &result.package {
+        Some(package) => format!("{} [{}]", result.name, package),
+        None => result.name.clone(),
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than tab/newline are invalid in XML 1.0.
+            c if c.is_control() && c != '\n' && c != '\t' && c != '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::FixAction;
+
+    fn result(category: &str, name: &str, status: Status) -> CheckResult {
+        CheckResult {
+            category: category.into(),
+            package: None,
+            name: name.into(),
+            status,
+            found: "not found".into(),
+            expected: ">=18 (.nvmrc)".into(),
+            fix: Some(FixAction::manual("nvm install 18")),
+            details: vec![],
+        }
+    }
+
+    #[test]
+    fn test_render_groups_by_category() {
+        let mut env = result("Environment", ".env", Status::Fail);
+        env.details = vec!["API_KEY — missing".into()];
+        let results = vec![
+            result("Runtime", "node", Status::Pass),
+            result("Runtime", "python", Status::Fail),
+            env,
+            result("Ports", ":3000", Status::Skip),
+        ];
+        let xml = render(&results);
+
+        assert!(xml.contains(
+            "<testsuites name=\"vitals\" tests=\"4\" failures=\"2\" skipped=\"1\" errors=\"0\">"
+        ));
+        assert!(xml.contains(
+            "<testsuite name=\"Runtime\" tests=\"2\" failures=\"1\" skipped=\"0\" errors=\"0\">"
+        ));
+        assert!(xml.contains("<testcase name=\"node\" classname=\"vitals.Runtime\"/>"));
+        assert!(xml.contains(
+            "<failure message=\"found not found, expected &gt;=18 (.nvmrc) — fix: nvm install 18\""
+        ));
+        assert!(xml.contains("<system-out>API_KEY — missing</system-out>"));
+        assert!(xml.contains("<skipped message=\"not found\"/>"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a<b & \"c\""), "a&lt;b &amp; &quot;c&quot;");
+        assert_eq!(escape("bell\u{7}"), "bell");
+    }
+}
@@ -1,7 +1,14 @@
 pub mod check;
 pub mod checks;
+pub mod compose;
 pub mod config;
 pub mod detect;
+pub mod docker;
+pub mod fix;
+pub mod github;
+pub mod junit;
 pub mod output;
+pub mod parallel;
 pub mod platform;
 pub mod version;
+pub mod workspace;
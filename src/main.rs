@@ -3,11 +3,15 @@ use std::path::PathBuf;
 use anyhow::Result;
 use clap::Parser;
 
-use vitals::checks;
+use vitals::check::Status;
+use vitals::checks::{Registry, Selection};
 use vitals::config;
-use vitals::detect;
-use vitals::output;
+use vitals::fix;
+use vitals::github;
+use vitals::junit;
+use vitals::output::{self, Format};
 use vitals::platform::Platform;
+use vitals::workspace;
 
 /// Universal development environment doctor.
 ///
@@ -25,10 +29,34 @@ struct Cli {
     #[arg(long)]
     ci: bool,
 
-    /// Skip check categories (comma-separated: runtime,deps,services,ports,env,commands)
+    /// Output format [default: github inside GitHub Actions, text otherwise]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Also write a JUnit XML report to this file
+    #[arg(long, value_name = "PATH")]
+    report_file: Option<PathBuf>,
+
+    /// Skip checks by id (comma-separated, e.g. services,ports; see --list-checks)
     #[arg(long, value_delimiter = ',')]
     skip: Vec<String>,
 
+    /// Run only these checks by id (comma-separated; see --list-checks)
+    #[arg(long, value_delimiter = ',')]
+    only: Vec<String>,
+
+    /// List available check ids and exit
+    #[arg(long)]
+    list_checks: bool,
+
+    /// Run suggested fixes for failing checks, then re-check
+    #[arg(long)]
+    fix: bool,
+
+    /// With --fix: run safe fixes without asking (installs, sudo and kill still ask)
+    #[arg(long, requires = "fix")]
+    yes: bool,
+
     /// Generate a .vitals.toml template in the current directory
     #[arg(long)]
     init: bool,
@@ -49,12 +77,68 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    let registry = Registry::builtin();
+
+    if cli.list_checks {
+        for check in registry.checks() {
+            println!(
+                "  {:<10} {:<14} {}",
+                check.id(),
+                check.category(),
+                check.description()
+            );
+        }
+        return Ok(());
+    }
+
+    let selection = Selection {
+        skip: cli.skip.clone(),
+        only: cli.only.clone(),
+    };
+    registry.validate(&selection)?;
+
+    let format = cli.format.unwrap_or(if github::is_actions() {
+        Format::Github
+    } else {
+        Format::Text
+    });
+
+    // Fix prompts and progress would interleave with the JSON document.
+    if cli.fix && format == Format::Json {
+        anyhow::bail!("--fix cannot be combined with --format json");
+    }
+
     let platform = Platform::detect();
     let config = config::load(&cli.path)?;
-    let ctx = detect::scan(&cli.path)?;
+    let ctx = workspace::scan(&cli.path, &config.workspace)?;
 
-    let results = checks::run_all(&ctx, &config, &platform, &cli.skip);
-    let (_passed, failed) = output::print_results(&results, cli.ci);
+    let results = registry.run_workspace(&ctx, &config, &platform, &selection)?;
+    if let Some(path) = &cli.report_file {
+        junit::write(path, &results)?;
+    }
+
+    let (_passed, mut failed) = match format {
+        Format::Text => output::print_results(&results, cli.ci),
+        Format::Json => output::print_json(&results, &ctx, &platform)?,
+        Format::Github => {
+            let counts = output::print_results(&results, true);
+            github::print_annotations(&results, &ctx);
+            github::write_step_summary(&results)?;
+            counts
+        }
+    };
+
+    if cli.fix && fix::fixable(&results) {
+        let attempts = fix::apply(&results, &ctx, cli.yes);
+        let ctx = workspace::scan(&cli.path, &config.workspace)?;
+        let rerun = registry.run_workspace(&ctx, &config, &platform, &selection)?;
+        fix::report(&attempts, &rerun);
+        if let Some(path) = &cli.report_file {
+            junit::write(path, &rerun)?;
+        }
+        failed = rerun.iter().filter(|r| r.status == Status::Fail).count();
+        println!();
+    }
 
     if failed > 0 {
         std::process::exit(1);
@@ -1,6 +1,91 @@
 use colored::*;
+use serde::Serialize;
 
 use crate::check::{CheckResult, Status};
+use crate::detect::ProjectContext;
+use crate::platform::Platform;
+
+/// Version of the `--format json` report schema. Bump on breaking changes.
+pub const JSON_SCHEMA_VERSION: u32 = 2;
+
+/// Report output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Colored, human-readable terminal output.
+    Text,
+    /// Machine-readable JSON report.
+    Json,
+    /// Plain text plus GitHub Actions annotations and job summary.
+    Github,
+}
+
+/// Aggregate counts over a set of check results.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Summary {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub warned: usize,
+    pub skipped: usize,
+}
+
+impl Summary {
+    /// Count results by status.
+    pub fn from_results(results: &[CheckResult]) -> Self {
+        let mut summary = Summary {
+            total: results.len(),
+            ..Default::default()
+        };
+        for result in results {
+            match result.status {
+                Status::Pass => summary.passed += 1,
+                Status::Fail => summary.failed += 1,
+                Status::Warn => summary.warned += 1,
+                Status::Skip => summary.skipped += 1,
+            }
+        }
+        summary
+    }
+}
+
+/// Top-level document emitted by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct JsonReport<'a> {
+    pub schema_version: u32,
+    pub vitals_version: &'static str,
+    pub platform: &'a Platform,
+    pub project: &'a ProjectContext,
+    pub summary: Summary,
+    pub results: &'a [CheckResult],
+}
+
+/// Render results as a pretty-printed JSON report.
+pub fn render_json(
+    results: &[CheckResult],
+    ctx: &ProjectContext,
+    platform: &Platform,
+) -> serde_json::Result<String> {
+    let report = JsonReport {
+        schema_version: JSON_SCHEMA_VERSION,
+        vitals_version: env!("CARGO_PKG_VERSION"),
+        platform,
+        project: ctx,
+        summary: Summary::from_results(results),
+        results,
+    };
+    serde_json::to_string_pretty(&report)
+}
+
+/// Print results as a JSON report on stdout. Returns (passed, failed).
+pub fn print_json(
+    results: &[CheckResult],
+    ctx: &ProjectContext,
+    platform: &Platform,
+) -> serde_json::Result<(usize, usize)> {
+    println!("{}", render_json(results, ctx, platform)?);
+    let summary = Summary::from_results(results);
+    Ok((summary.passed, summary.failed))
+}
 
 /// Print all check results grouped by category. Returns (passed, failed).
 pub fn print_results(results: &[CheckResult], ci: bool) -> (usize, usize) {
@@ -29,10 +114,25 @@ pub fn print_results(results: &[CheckResult], ci: bool) -> (usize, usize) {
     }
 
     let mut current_category = String::new();
+    let mut current_package: Option<&str> = None;
+    let workspace = results.iter().any(|r| r.package.is_some());
     let mut passed = 0usize;
     let mut failed = 0usize;
 
-    for result in results {
+    for (i, result) in results.iter().enumerate() {
+        // Package header (monorepos only)
+        let package = result.package.as_deref();
+        if workspace && (i == 0 || package != current_package) {
+            println!();
+            println!(
+                "  {} {}",
+                "▸".cyan().bold(),
+                package.unwrap_or("(root)").bold()
+            );
+            current_package = package;
+            current_category.clear();
+        }
+
         // Category header
         if result.category != current_category {
             println!();
@@ -73,7 +173,7 @@ pub fn print_results(results: &[CheckResult], ci: bool) -> (usize, usize) {
 
         // Fix suggestion — show for failures
         let fix_str = match &result.fix {
-            Some(fix) => format!("  {} {}", "→".dimmed(), fix.yellow()),
+            Some(fix) => format!("  {} {}", "→".dimmed(), fix.command.yellow()),
             None => String::new(),
         };
 
@@ -116,3 +216,52 @@ pub fn print_results(results: &[CheckResult], ci: bool) -> (usize, usize) {
 
     (passed, failed)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect;
+
+    fn result(name: &str, status: Status) -> CheckResult {
+        CheckResult {
+            category: "Runtime".into(),
+            package: None,
+            name: name.into(),
+            status,
+            found: "v1.0.0".into(),
+            expected: "installed".into(),
+            fix: None,
+            details: vec![],
+        }
+    }
+
+    #[test]
+    fn test_summary_counts() {
+        let results = vec![
+            result("a", Status::Pass),
+            result("b", Status::Fail),
+            result("c", Status::Warn),
+            result("d", Status::Pass),
+        ];
+        let summary = Summary::from_results(&results);
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.passed, 2);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.warned, 1);
+        assert_eq!(summary.skipped, 0);
+    }
+
+    #[test]
+    fn test_render_json_schema() {
+        let ctx = detect::scan(std::path::Path::new("/nonexistent")).unwrap();
+        let results = vec![result("node", Status::Fail)];
+        let json = render_json(&results, &ctx, &Platform::MacOS).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(value["platform"], "macos");
+        assert_eq!(value["summary"]["failed"], 1);
+        assert_eq!(value["results"][0]["status"], "fail");
+        assert_eq!(value["results"][0]["fix"], serde_json::Value::Null);
+    }
+}
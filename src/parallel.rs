@@ -0,0 +1,90 @@
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Default number of worker threads: one per core, capped at 8.
+pub fn default_workers() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+        .min(8)
+}
+
+/// Apply `f` to every item on a bounded pool of `workers` threads.
+///
+/// Results come back in input order regardless of completion order.
+pub fn map<T, R, F>(items: Vec<T>, workers: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let len = items.len();
+    if len <= 1 || workers <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let slots: Mutex<Vec<Option<R>>> = Mutex::new((0..len).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.min(len) {
+            scope.spawn(|| {
+                loop {
+                    let next = queue.lock().unwrap().next();
+                    let Some((i, item)) = next else { break };
+                    let result = f(item);
+                    slots.lock().unwrap()[i] = Some(result);
+                }
+            });
+        }
+    });
+
+    slots
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every item is processed"))
+        .collect()
+}
+
+/// Run `f` on its own thread and wait at most `timeout` for it.
+///
+/// Returns `None` on timeout (or if `f` panics). A timed-out closure is left
+/// running in the background; its result is discarded.
+pub fn with_timeout<R, F>(timeout: Duration, f: F) -> Option<R>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(f());
+    });
+    rx.recv_timeout(timeout).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_preserves_order() {
+        let out = map((0..20).collect(), 4, |i: u64| {
+            thread::sleep(Duration::from_millis(20 - i));
+            i * 2
+        });
+        assert_eq!(out, (0..20).map(|i| i * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        assert_eq!(with_timeout(Duration::from_secs(1), || 42), Some(42));
+        let slow = with_timeout(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_millis(500));
+            1
+        });
+        assert_eq!(slow, None);
+    }
+}
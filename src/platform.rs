@@ -1,7 +1,10 @@
 use std::fs;
 
+use serde::Serialize;
+
 /// Detected host platform, used for tailoring fix suggestions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Platform {
     MacOS,
     Debian,
@@ -59,11 +62,28 @@ impl Platform {
                 _ => "golang",
             },
             "ruby" => "ruby",
+            "php" => match self {
+                Platform::MacOS | Platform::Arch => "php",
+                Platform::Alpine => "php83",
+                _ => "php-cli",
+            },
+            "composer" => "composer",
+            "dotnet" => match self {
+                Platform::MacOS => return "brew install --cask dotnet-sdk".into(),
+                _ => "dotnet-sdk",
+            },
             "java" => match self {
                 Platform::MacOS => "openjdk",
                 Platform::Debian => "default-jdk",
                 _ => "java-latest-openjdk",
             },
+            "deno" => match self {
+                Platform::MacOS | Platform::Arch => "deno",
+                _ => return "curl -fsSL https://deno.land/install.sh | sh".into(),
+            },
+            "bundler" => return "gem install bundler".into(),
+            "maven" => "maven",
+            "gradle" => "gradle",
             "docker" => match self {
                 Platform::MacOS => return "brew install --cask docker".into(),
                 _ => "docker.io",
@@ -1,22 +1,47 @@
 use regex::Regex;
 use semver::{Version, VersionReq};
-use std::process::Command;
+use std::path::Path;
+use std::process::{Command, Output};
 
 /// Run a command and return its stdout (or stderr) as a trimmed string.
 pub fn run_cmd(name: &str, args: &[&str]) -> Option<String> {
-    Command::new(name).args(args).output().ok().and_then(|out| {
-        let stdout = String::from_utf8_lossy(&out.stdout).trim().to_string();
-        let stderr = String::from_utf8_lossy(&out.stderr).trim().to_string();
-        if out.status.success() && !stdout.is_empty() {
-            Some(stdout)
-        } else if !stderr.is_empty() {
-            Some(stderr)
-        } else if !stdout.is_empty() {
-            Some(stdout)
-        } else {
-            None
-        }
-    })
+    Command::new(name)
+        .args(args)
+        .output()
+        .ok()
+        .and_then(output_text)
+}
+
+/// Like [`run_cmd`], but in `dir`, so version-manager and Corepack shims
+/// resolve the project's pinned version. Corepack may not block on its
+/// download prompt.
+pub fn run_cmd_in(dir: &Path, name: &str, args: &[&str]) -> Option<String> {
+    run_cmd_env(dir, name, args, &[("COREPACK_ENABLE_DOWNLOAD_PROMPT", "0")])
+}
+
+/// Like [`run_cmd_in`], with extra environment variables.
+pub fn run_cmd_env(dir: &Path, name: &str, args: &[&str], env: &[(&str, &str)]) -> Option<String> {
+    Command::new(name)
+        .args(args)
+        .current_dir(dir)
+        .envs(env.iter().copied())
+        .output()
+        .ok()
+        .and_then(output_text)
+}
+
+fn output_text(out: Output) -> Option<String> {
+    let stdout = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    let stderr = String::from_utf8_lossy(&out.stderr).trim().to_string();
+    if out.status.success() && !stdout.is_empty() {
+        Some(stdout)
+    } else if !stderr.is_empty() {
+        Some(stderr)
+    } else if !stdout.is_empty() {
+        Some(stdout)
+    } else {
+        None
+    }
 }
 
 /// Extract a version string (X.Y.Z or X.Y) from arbitrary command output.
@@ -27,9 +52,30 @@ pub fn extract_version(output: &str) -> Option<String> {
         .map(|m| m.as_str().to_string())
 }
 
+/// Extract the first capture group of `pattern` from command output, for
+/// tools that print several versions (e.g., Elixir and its OTP release).
+pub fn extract_version_with(output: &str, pattern: &str) -> Option<String> {
+    let re = Regex::new(pattern).ok()?;
+    re.captures(output)
+        .and_then(|cap| cap.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
 /// Normalize a version string to strict semver (X.Y.Z).
 pub fn normalize_version(v: &str) -> String {
     let v = v.trim().trim_start_matches(['v', 'V']);
+
+    // Java 8 and older report "1.8.0_392": the real major is the second
+    // part and the update number is the patch ("8.0.392").
+    if let Some((base, update)) = v.split_once('_') {
+        if let Some(rest) = base.strip_prefix("1.") {
+            let mut parts = rest.split('.');
+            let major = parts.next().unwrap_or("0");
+            let minor = parts.next().unwrap_or("0");
+            return format!("{}.{}.{}", major, minor, update);
+        }
+    }
+
     let parts: Vec<&str> = v.split('.').collect();
     match parts.len() {
         0 => "0.0.0".to_string(),
@@ -39,6 +85,30 @@ pub fn normalize_version(v: &str) -> String {
     }
 }
 
+/// Java before 9 numbered releases "1.x": "1.8" → "8", "1.8.0" → "8.0".
+/// Other versions are returned unchanged.
+pub fn java_version(v: &str) -> String {
+    match v.strip_prefix("1.") {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest.to_string(),
+        _ => v.to_string(),
+    }
+}
+
+/// Apply [`java_version`] to every version in a Java requirement:
+/// ">=1.8" → ">=8", "1.8" → "8".
+pub fn java_requirement(req: &str) -> String {
+    match Regex::new(r"(^|[^\d.])1\.(\d)") {
+        Ok(re) => re.replace_all(req, "${1}${2}").into_owned(),
+        Err(_) => req.to_string(),
+    }
+}
+
+/// Java 8's "1.8.0_392" form.
+fn is_legacy_java(v: &str) -> bool {
+    v.split_once('_')
+        .is_some_and(|(base, _)| base.trim_start_matches(['v', 'V']).starts_with("1."))
+}
+
 /// Normalize a requirement string into a semver VersionReq.
 /// "20" → ">=20.0.0, <21.0.0"
 /// ">=18" → ">=18.0.0"
@@ -46,6 +116,65 @@ pub fn normalize_version(v: &str) -> String {
 pub fn normalize_requirement(req: &str) -> String {
     let req = req.trim();
 
+    // "~> 3.2, >= 3.2.2" (Gemfile, mix): every clause applies.
+    if req.contains(',') {
+        return req
+            .split(',')
+            .map(str::trim)
+            .filter(|clause| !clause.is_empty())
+            .map(normalize_requirement)
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+
+    // Elixir writes ">= 1.14.0 and < 2.0.0".
+    if req.contains(" and ") {
+        return normalize_requirement(&req.replace(" and ", " "));
+    }
+
+    // ">=18 <21": whitespace-separated comparators are ANDed (npm, Composer).
+    if req.split_whitespace().nth(1).is_some() {
+        let mut parts = Vec::new();
+        let mut pending = String::new();
+        for token in req.split_whitespace() {
+            pending.push_str(token);
+            // A bare operator (">= 18") belongs to the next token.
+            if token
+                .chars()
+                .all(|c| matches!(c, '<' | '>' | '=' | '^' | '~'))
+            {
+                continue;
+            }
+            parts.push(normalize_requirement(&pending));
+            pending.clear();
+        }
+        return parts.join(", ");
+    }
+
+    // "~> 1.15" (Elixir, Ruby) allows the last given part to grow:
+    // ">=1.15.0, <2.0.0"; "~> 1.15.2" is ">=1.15.2, <1.16.0".
+    // Cargo's "~1.15" would stop at 1.16.
+    if let Some(v) = req.strip_prefix("~>") {
+        let parts: Vec<u64> = v.trim().split('.').filter_map(|p| p.parse().ok()).collect();
+        return match parts.as_slice() {
+            [major] | [major, _] => format!(
+                ">={}.{}.0, <{}.0.0",
+                major,
+                parts.get(1).unwrap_or(&0),
+                major + 1
+            ),
+            [major, minor, patch, ..] => format!(
+                ">={}.{}.{}, <{}.{}.0",
+                major,
+                minor,
+                patch,
+                major,
+                minor + 1
+            ),
+            [] => ">=0.0.0".to_string(),
+        };
+    }
+
     // If it already has an operator, use as-is.
     if req.starts_with(">=")
         || req.starts_with("<=")
@@ -54,7 +183,6 @@ pub fn normalize_requirement(req: &str) -> String {
         || req.starts_with('=')
         || req.starts_with('^')
         || req.starts_with('~')
-        || req.contains(',')
     {
         return req.to_string();
     }
@@ -76,10 +204,38 @@ pub fn normalize_requirement(req: &str) -> String {
     }
 }
 
+/// The version a requirement starts from, to pass to an installer:
+/// ">=8.7" → "8.7", ">=2.0,<3.0" → "2.0". Empty for upper bounds ("<2").
+pub fn min_version(req: &str) -> &str {
+    let first = req
+        .split([',', ' '])
+        .find(|c| !c.is_empty())
+        .unwrap_or_default();
+    if first.starts_with('<') {
+        return "";
+    }
+    first.trim_start_matches(['>', '=', '^', '~', 'v'])
+}
+
 /// Check if a found version meets a requirement string.
+///
+/// `||` (or Elixir's `or`) separates alternatives (`"^18 || ^20"`); any one
+/// may match.
 pub fn meets_requirement(found: &str, required: &str) -> bool {
+    if required.contains("||") || required.contains(" or ") {
+        return required
+            .split("||")
+            .flat_map(|alt| alt.split(" or "))
+            .any(|alt| meets_requirement(found, alt));
+    }
+    // A legacy Java version is compared in the same numbering as the
+    // requirement: "1.8" means 8.
+    let required = match is_legacy_java(found) {
+        true => java_requirement(required),
+        false => required.to_string(),
+    };
     let norm_found = normalize_version(found);
-    let norm_req = normalize_requirement(required);
+    let norm_req = normalize_requirement(&required);
     if let (Ok(version), Ok(req)) = (Version::parse(&norm_found), VersionReq::parse(&norm_req)) {
         return req.matches(&version);
     }
@@ -90,6 +246,27 @@ pub fn meets_requirement(found: &str, required: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_java_legacy_version() {
+        assert_eq!(normalize_version("1.8.0_392"), "8.0.392");
+        assert!(meets_requirement("1.8.0_392", "8"));
+        assert!(meets_requirement("1.8.0_392", "1.8"));
+        assert!(!meets_requirement("17.0.2", &java_requirement("1.8")));
+        assert!(meets_requirement("17.0.2", &java_requirement(">=1.8")));
+        assert_eq!(java_requirement(">=1.8, <11"), ">=8, <11");
+        assert!(!meets_requirement("1.8.0_392", ">=11"));
+        assert!(meets_requirement("17.0.2", ">=11"));
+        assert_eq!(normalize_version("1.75.0"), "1.75.0");
+    }
+
+    #[test]
+    fn test_min_version() {
+        assert_eq!(min_version(">=8.7"), "8.7");
+        assert_eq!(min_version(">=2.0,<3.0"), "2.0");
+        assert_eq!(min_version("^2.4.10"), "2.4.10");
+        assert_eq!(min_version("<2"), "");
+    }
+
     #[test]
     fn test_extract_version() {
         assert_eq!(extract_version("v20.10.0"), Some("20.10.0".into()));
@@ -120,5 +297,27 @@ mod tests {
         assert!(meets_requirement("1.75.0", ">=1.70"));
         assert!(meets_requirement("20.10.0", "20"));
         assert!(!meets_requirement("21.0.0", "20"));
+        assert!(meets_requirement("20.1.0", "^18 || ^20"));
+        assert!(!meets_requirement("19.0.0", "^18 || ^20"));
+        assert!(meets_requirement("20.1.0", ">= 18 <21"));
+        assert!(!meets_requirement("21.0.0", ">=18 <21"));
+    }
+
+    #[test]
+    fn test_pessimistic_requirement() {
+        assert_eq!(normalize_requirement("~> 1.15"), ">=1.15.0, <2.0.0");
+        assert_eq!(normalize_requirement("~> 1.15.2"), ">=1.15.2, <1.16.0");
+        assert!(meets_requirement("1.17.3", "~> 1.15"));
+        assert!(!meets_requirement("2.0.0", "~> 1.15"));
+        assert!(!meets_requirement("1.16.0", "~> 1.15.2"));
+        assert!(meets_requirement("1.16.1", "~> 1.14 or ~> 1.16.0"));
+        assert_eq!(
+            normalize_requirement("~> 3.2, >= 3.2.2"),
+            ">=3.2.0, <4.0.0, >=3.2.2"
+        );
+        assert!(meets_requirement("3.3.0", "~> 3.2, >= 3.2.2"));
+        assert!(!meets_requirement("3.2.1", "~> 3.2, >= 3.2.2"));
+        assert!(!meets_requirement("4.0.0", "~> 3.2, >= 3.2.2"));
+        assert!(meets_requirement("1.14.2", ">= 1.14.0 and < 2.0.0"));
     }
 }
@@ -0,0 +1,407 @@
+//! Monorepo support: find workspace members and scan each one.
+//!
+//! Members come from `pnpm-workspace.yaml`, `package.json` `workspaces`,
+//! Cargo `[workspace] members`, `go.work` `use` directives and the
+//! `[workspace]` section of `.vitals.toml`. Every member gets its own
+//! [`ProjectContext`], stored on the root context.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::check::CheckResult;
+use crate::config::WorkspaceConfig;
+use crate::detect::{self, ProjectContext};
+
+/// Directories never searched for members.
+const SKIP_DIRS: &[&str] = &[
+    "node_modules",
+    "target",
+    "vendor",
+    "dist",
+    "build",
+    "_build",
+];
+
+/// How deep `**` patterns search below the workspace root.
+const MAX_DEPTH: usize = 6;
+
+/// A workspace member package.
+#[derive(Debug, Clone, Serialize)]
+pub struct Member {
+    /// Path relative to the workspace root (e.g., "packages/web").
+    pub name: String,
+    pub project: ProjectContext,
+}
+
+/// Member globs declared by one workspace manifest.
+struct Patterns {
+    include: Vec<String>,
+    exclude: Vec<String>,
+    /// File a directory must contain to count as a member.
+    manifest: Option<&'static str>,
+}
+
+/// Scan the root directory and every workspace member below it.
+pub fn scan(dir: &Path, config: &WorkspaceConfig) -> Result<ProjectContext> {
+    let mut root = detect::scan(dir)?;
+
+    for (name, member_dir) in discover(&root.dir, config) {
+        let mut project = detect::scan(&member_dir)?;
+        inherit(&root, &mut project);
+        root.members.push(Member { name, project });
+    }
+    Ok(root)
+}
+
+/// Workspace members as (relative name, directory), sorted by name.
+pub fn discover(root: &Path, config: &WorkspaceConfig) -> Vec<(String, PathBuf)> {
+    let mut sources = Vec::new();
+    sources.extend(pnpm_patterns(root));
+    sources.extend(npm_patterns(root));
+    sources.extend(cargo_patterns(root));
+    sources.extend(go_work_patterns(root));
+    if !config.members.is_empty() {
+        sources.push(Patterns {
+            include: config.members.clone(),
+            exclude: vec![],
+            manifest: None,
+        });
+    }
+    if sources.is_empty() {
+        return vec![];
+    }
+
+    let candidates = subdirs(root);
+    let mut members: Vec<(String, PathBuf)> = Vec::new();
+    for source in &sources {
+        for (name, dir) in &candidates {
+            let wanted = source.include.iter().any(|p| glob_match(p, name))
+                && !source.exclude.iter().any(|p| glob_match(p, name))
+                && !config.exclude.iter().any(|p| glob_match(p, name))
+                && source.manifest.is_none_or(|m| dir.join(m).is_file());
+            if wanted && !members.iter().any(|(n, _)| n == name) {
+                members.push((name.clone(), dir.clone()));
+            }
+        }
+    }
+    members.sort();
+    members
+}
+
+// ── Manifests ───────────────────────────────────────────────────────────────
+
+/// Split `!`-prefixed exclusions out of a pattern list.
+fn split_negated(patterns: Vec<String>, manifest: &'static str) -> Option<Patterns> {
+    let (exclude, include): (Vec<String>, Vec<String>) =
+        patterns.into_iter().partition(|p| p.starts_with('!'));
+    if include.is_empty() {
+        return None;
+    }
+    Some(Patterns {
+        include: include.iter().map(|p| normalize(p)).collect(),
+        exclude: exclude.iter().map(|p| normalize(&p[1..])).collect(),
+        manifest: Some(manifest),
+    })
+}
+
+fn pnpm_patterns(root: &Path) -> Option<Patterns> {
+    let content = fs::read_to_string(root.join("pnpm-workspace.yaml")).ok()?;
+    let doc: serde_yaml::Value = serde_yaml::from_str(&content).ok()?;
+    let packages = doc.get("packages")?.as_sequence()?;
+    let patterns = packages
+        .iter()
+        .filter_map(|p| p.as_str().map(str::to_string))
+        .collect();
+    split_negated(patterns, "package.json")
+}
+
+fn npm_patterns(root: &Path) -> Option<Patterns> {
+    let content = fs::read_to_string(root.join("package.json")).ok()?;
+    let pkg: serde_json::Value = serde_json::from_str(&content).ok()?;
+    // Either `"workspaces": [...]` or yarn's `"workspaces": {"packages": [...]}`.
+    let workspaces = pkg.get("workspaces")?;
+    let list = workspaces
+        .as_array()
+        .or_else(|| workspaces.get("packages")?.as_array())?;
+    let patterns = list
+        .iter()
+        .filter_map(|p| p.as_str().map(str::to_string))
+        .collect();
+    split_negated(patterns, "package.json")
+}
+
+fn cargo_patterns(root: &Path) -> Option<Patterns> {
+    let content = fs::read_to_string(root.join("Cargo.toml")).ok()?;
+    let manifest: toml::Value = toml::from_str(&content).ok()?;
+    let workspace = manifest.get("workspace")?;
+    let strings = |key: &str| -> Vec<String> {
+        workspace
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_str()).map(normalize).collect())
+            .unwrap_or_default()
+    };
+    Some(Patterns {
+        include: strings("members"),
+        exclude: strings("exclude"),
+        manifest: Some("Cargo.toml"),
+    })
+}
+
+fn go_work_patterns(root: &Path) -> Option<Patterns> {
+    let content = fs::read_to_string(root.join("go.work")).ok()?;
+    Some(Patterns {
+        include: parse_go_work(&content),
+        exclude: vec![],
+        manifest: Some("go.mod"),
+    })
+}
+
+/// Module directories from `use ./api` and `use ( ... )` blocks.
+fn parse_go_work(content: &str) -> Vec<String> {
+    let mut dirs = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if in_block {
+            if line == ")" {
+                in_block = false;
+            } else if !line.is_empty() {
+                dirs.push(normalize(line.trim_matches('"')));
+            }
+        } else if let Some(rest) = line.strip_prefix("use") {
+            if !rest.starts_with([' ', '\t', '(']) {
+                continue;
+            }
+            match rest.trim() {
+                "(" => in_block = true,
+                dir => dirs.push(normalize(dir.trim_matches('"'))),
+            }
+        }
+    }
+    dirs
+}
+
+/// "./packages/*/" → "packages/*".
+fn normalize(pattern: &str) -> String {
+    let p = pattern.trim();
+    let p = p.strip_prefix("./").unwrap_or(p);
+    p.trim_end_matches('/').to_string()
+}
+
+// ── Globs ───────────────────────────────────────────────────────────────────
+
+/// Every directory below `root` (relative name, path), up to [`MAX_DEPTH`].
+fn subdirs(root: &Path) -> Vec<(String, PathBuf)> {
+    let mut found = Vec::new();
+    let mut stack = vec![(String::new(), root.to_path_buf(), 0)];
+    while let Some((prefix, dir, depth)) = stack.pop() {
+        if depth == MAX_DEPTH {
+            continue;
+        }
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            if name.starts_with('.') || SKIP_DIRS.contains(&name.as_str()) {
+                continue;
+            }
+            if !entry.file_type().is_ok_and(|t| t.is_dir()) {
+                continue;
+            }
+            let rel = if prefix.is_empty() {
+                name
+            } else {
+                format!("{}/{}", prefix, name)
+            };
+            found.push((rel.clone(), entry.path()));
+            stack.push((rel, entry.path(), depth + 1));
+        }
+    }
+    found
+}
+
+/// Match a relative path against a glob: `*` and `?` within a segment,
+/// `**` across any number of segments.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').collect();
+    match_segments(&pattern, &path)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|i| match_segments(rest, &path[i..])),
+        Some((seg, rest)) => {
+            !path.is_empty() && wildcard(seg, path[0]) && match_segments(rest, &path[1..])
+        }
+    }
+}
+
+fn wildcard(pattern: &str, name: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let n: Vec<char> = name.chars().collect();
+    fn go(p: &[char], n: &[char]) -> bool {
+        match p.split_first() {
+            None => n.is_empty(),
+            Some(('*', rest)) => (0..=n.len()).any(|i| go(rest, &n[i..])),
+            Some(('?', rest)) => !n.is_empty() && go(rest, &n[1..]),
+            Some((c, rest)) => n.first() == Some(c) && go(rest, &n[1..]),
+        }
+    }
+    go(&p, &n)
+}
+
+// ── Inheritance ─────────────────────────────────────────────────────────────
+
+/// Apply what members get from the workspace root: toolchain pins
+/// (`rust-toolchain.toml`, `.nvmrc`, `.tool-versions`, ... are looked up from
+/// parent directories) and hoisted `node_modules`.
+fn inherit(root: &ProjectContext, member: &mut ProjectContext) {
+    for req in member
+        .runtimes
+        .iter_mut()
+        .filter(|r| r.version_req.is_none())
+    {
+        if let Some(pinned) = root
+            .runtimes
+            .iter()
+            .find(|r| r.name == req.name && r.version_req.is_some())
+        {
+            req.version_req.clone_from(&pinned.version_req);
+            req.source.clone_from(&pinned.source);
+        }
+    }
+
+    // npm, yarn and bun hoist member dependencies into the root node_modules;
+    // pnpm links a node_modules into every member.
+    let hoisted = root
+        .node
+        .as_ref()
+        .is_some_and(|n| n.package_manager != "pnpm")
+        && root.deps.iter().any(|d| d.name == "node_modules");
+    if hoisted {
+        member.deps.retain(|d| d.name != "node_modules" || d.exists);
+    }
+}
+
+// ── Results ─────────────────────────────────────────────────────────────────
+
+/// Categories whose results describe the machine rather than a package
+/// directory, so identical results from several packages are one finding.
+const SHARED_CATEGORIES: &[&str] = &["Runtime", "Services", "Containers", "Ports"];
+
+/// Collapse duplicate machine-wide results across packages and order the
+/// rest by package (root first), then by category.
+///
+/// A result reported identically by several packages is kept once and
+/// moved to the workspace root (`package: None`).
+pub fn merge(results: Vec<CheckResult>, packages: &[String]) -> Vec<CheckResult> {
+    let mut merged: Vec<CheckResult> = Vec::new();
+    for result in results {
+        let shared = SHARED_CATEGORIES.contains(&result.category.as_str());
+        match merged
+            .iter_mut()
+            .find(|r| shared && same_finding(r, &result))
+        {
+            Some(kept) => {
+                if kept.package != result.package {
+                    kept.package = None;
+                }
+            }
+            None => merged.push(result),
+        }
+    }
+
+    let mut categories: Vec<String> = Vec::new();
+    for r in &merged {
+        if !categories.contains(&r.category) {
+            categories.push(r.category.clone());
+        }
+    }
+    let package_rank = |p: &Option<String>| match p {
+        None => 0,
+        Some(name) => {
+            1 + packages
+                .iter()
+                .position(|n| n == name)
+                .unwrap_or(packages.len())
+        }
+    };
+    let category_rank = |c: &String| categories.iter().position(|x| x == c).unwrap_or(0);
+    merged.sort_by_key(|r| (package_rank(&r.package), category_rank(&r.category)));
+    merged
+}
+
+fn same_finding(a: &CheckResult, b: &CheckResult) -> bool {
+    a.category == b.category
+        && a.name == b.name
+        && a.status == b.status
+        && a.found == b.found
+        && a.expected == b.expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("packages/*", "packages/web"));
+        assert!(!glob_match("packages/*", "packages/web/src"));
+        assert!(glob_match("apps/**", "apps/a/b"));
+        assert!(glob_match("**/svc-?", "services/py/svc-1"));
+        assert!(glob_match("crates/*-sys", "crates/zlib-sys"));
+        assert!(!glob_match("crates/*-sys", "crates/zlib"));
+        assert!(glob_match("services/api", "services/api"));
+    }
+
+    #[test]
+    fn test_merge_shared_results() {
+        let result = |package: Option<&str>, category: &str, name: &str| CheckResult {
+            category: category.into(),
+            package: package.map(str::to_string),
+            name: name.into(),
+            status: crate::check::Status::Pass,
+            found: "v20.10.0".into(),
+            expected: "installed".into(),
+            fix: None,
+            details: vec![],
+        };
+        let packages = vec!["apps/a".to_string(), "apps/b".to_string()];
+        let merged = merge(
+            vec![
+                result(Some("apps/a"), "Runtime", "node"),
+                result(Some("apps/a"), "Dependencies", "node_modules"),
+                result(Some("apps/b"), "Runtime", "node"),
+                result(Some("apps/b"), "Dependencies", "node_modules"),
+            ],
+            &packages,
+        );
+        let summary: Vec<(Option<&str>, &str)> = merged
+            .iter()
+            .map(|r| (r.package.as_deref(), r.name.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (None, "node"),
+                (Some("apps/a"), "node_modules"),
+                (Some("apps/b"), "node_modules"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_go_work() {
+        let content = "go 1.22\n\nuse ./api // the API\nuse (\n\t./tools\n\t\"./cmd/cli\"\n)\n";
+        assert_eq!(parse_go_work(content), vec!["api", "tools", "cmd/cli"]);
+    }
+}